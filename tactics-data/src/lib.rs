@@ -8,21 +8,87 @@
  * See https://quint-lang.org/tactics for more information
  */
 
-use std::{fs::File, io::Read};
+use std::{collections::BTreeMap, fs::File, io::Read};
+
+use arrow2::{
+    array::Array as ArrowArray,
+    chunk::Chunk,
+    datatypes::{PhysicalType, PrimitiveType},
+    io::{ipc, parquet},
+};
 
 use csv::{ReaderBuilder, StringRecord};
 use csv::ErrorKind::Seek;
 
 use itertools::Itertools;
 
-use ndarray::{
-    iter::AxisChunksIter, Array, ArrayView, Axis, Dimension, IntoDimension, Ix, RemoveAxis, Zip,
-};
+use ndarray::{Array, ArrayView, Axis, CowArray, Dimension, IntoDimension, Ix, Ix2, RemoveAxis, Slice, Zip};
 
 use rand::{rngs::StdRng, Rng, SeedableRng, random};
 
 use serde::de::DeserializeOwned;
 
+/// Converts a single Arrow column into a plain `f32` vector, downcasting from whichever numeric
+/// or boolean physical type it was stored as.
+///
+/// # Panics
+///
+/// If the column holds a type that cannot be interpreted as numeric.
+fn column_to_f32(column: &dyn ArrowArray) -> Vec<f32> {
+    use arrow2::array::{BooleanArray, Float32Array, Float64Array, Int32Array, Int64Array};
+
+    match column.data_type().to_physical_type() {
+        PhysicalType::Boolean => column
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap()
+            .iter()
+            .map(|value| if value.unwrap_or(false) { 1. } else { 0. })
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Int32) => column
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap()
+            .iter()
+            .map(|value| value.copied().unwrap_or(0) as f32)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Int64) => column
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap()
+            .iter()
+            .map(|value| value.copied().unwrap_or(0) as f32)
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Float32) => column
+            .as_any()
+            .downcast_ref::<Float32Array>()
+            .unwrap()
+            .iter()
+            .map(|value| value.copied().unwrap_or(0.))
+            .collect(),
+        PhysicalType::Primitive(PrimitiveType::Float64) => column
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap()
+            .iter()
+            .map(|value| value.copied().unwrap_or(0.) as f32)
+            .collect(),
+        other => panic!("error: column of physical type {other:?} cannot be read as numeric."),
+    }
+}
+
+/// Appends the rows of a columnar Arrow `Chunk` to `into` one after another, each row's fields in
+/// column order, so `into` can back a `Dataset`'s `Array` directly.
+fn chunk_rows_into(chunk: &Chunk<Box<dyn ArrowArray>>, into: &mut Vec<f32>) {
+    let columns: Vec<Vec<f32>> = chunk.arrays().iter().map(|column| column_to_f32(column.as_ref())).collect();
+
+    for row in 0..chunk.len() {
+        for column in &columns {
+            into.push(column[row]);
+        }
+    }
+}
+
 /// Computes the correct shape for the stacked records of a dataset.
 /// 该函数实现了一个功能，接受一个shape维度Dimension 和一个usize的rows，然后创建一个新的Dimension，维度为传入
 /// 的 + 1,并将第一维度的值设置为rows， 之后将传入的Dimension的值赋予新的Dimension剩余的维度值
@@ -34,6 +100,263 @@ fn stacked_shape<D: Dimension>(rows: usize, shape: D) -> D::Larger {
     new_shape
 }
 
+/// How a single CSV column should be converted into one or more `f32` fields, used by
+/// [`DataLoader::with_schema`] in place of the all-`f32` assumption of `from_reader_fn`.
+#[derive(Clone)]
+pub enum Conversion {
+    /// Left out of the converted record entirely.
+    Bytes,
+    /// Parsed as a floating point number.
+    Float,
+    /// Parsed as an integer, then widened to `f32`.
+    Integer,
+    /// `"true"`/`"false"` (case-insensitive) mapped to `1.0`/`0.0`.
+    Boolean,
+    /// Parsed as a Unix timestamp already expressed in seconds.
+    Timestamp,
+    /// Parsed with the given `chrono` format string into a naive Unix timestamp.
+    TimestampFmt(String),
+    /// Parsed with the given `chrono` format string into a timezone-aware Unix timestamp.
+    TimestampTzFmt(String),
+    /// Mapped to a stable 0-based index the first time each distinct value is seen, optionally
+    /// one-hot expanded into one output column per distinct value discovered.
+    Categorical { one_hot: bool },
+}
+
+/// Maps each column of a CSV record to the [`Conversion`] used to turn it into one or more `f32`
+/// fields.
+///
+/// Stable label-to-index mappings discovered for [`Conversion::Categorical`] columns while loading
+/// are retained on the `Schema`, so the same encoding can be queried and reused on a held-out set.
+#[derive(Default)]
+pub struct Schema {
+    conversions: BTreeMap<usize, Conversion>,
+    categories: BTreeMap<usize, Vec<String>>,
+}
+
+impl Schema {
+    /// Creates an empty schema. Columns with no registered conversion default to `Float`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers how column `id` should be converted.
+    pub fn with_column(mut self, id: usize, conversion: Conversion) -> Self {
+        self.conversions.insert(id, conversion);
+        self
+    }
+
+    /// Returns the stable labels discovered so far for categorical column `id`, in assignment
+    /// order; a label's position in this slice is the index it was (or will be) encoded as.
+    pub fn categories(&self, id: usize) -> &[String] {
+        self.categories.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    fn column_width(&self, id: usize) -> usize {
+        match self.conversions.get(&id) {
+            Some(Conversion::Bytes) => 0,
+            Some(Conversion::Categorical { one_hot: true }) => {
+                self.categories.get(&id).map_or(1, |labels| labels.len().max(1))
+            }
+            _ => 1,
+        }
+    }
+
+    fn record_width(&self, raw_columns: usize) -> usize {
+        (0..raw_columns).map(|id| self.column_width(id)).sum()
+    }
+
+    fn category_index(&mut self, id: usize, label: &str) -> usize {
+        let labels = self.categories.entry(id).or_default();
+        match labels.iter().position(|known| known == label) {
+            Some(index) => index,
+            None => {
+                labels.push(label.to_owned());
+                labels.len() - 1
+            }
+        }
+    }
+
+    /// Converts a single raw field of column `id` into zero or more `f32` fields, appending them
+    /// to `into`.
+    ///
+    /// # Panics
+    ///
+    /// If `field` cannot be parsed according to its registered [`Conversion`].
+    fn convert(&mut self, id: usize, field: &str, into: &mut Vec<f32>) {
+        match self.conversions.get(&id).cloned().unwrap_or(Conversion::Float) {
+            Conversion::Bytes => {}
+            Conversion::Float => into.push(field.parse().unwrap()),
+            Conversion::Integer => into.push(field.parse::<i64>().unwrap() as f32),
+            Conversion::Boolean => into.push(if field.eq_ignore_ascii_case("true") { 1. } else { 0. }),
+            Conversion::Timestamp => into.push(field.parse::<i64>().unwrap() as f32),
+            Conversion::TimestampFmt(fmt) => into.push(
+                chrono::NaiveDateTime::parse_from_str(field, &fmt)
+                    .unwrap()
+                    .and_utc()
+                    .timestamp() as f32,
+            ),
+            Conversion::TimestampTzFmt(fmt) => {
+                into.push(chrono::DateTime::parse_from_str(field, &fmt).unwrap().timestamp() as f32)
+            }
+            Conversion::Categorical { one_hot } => {
+                let index = self.category_index(id, field);
+                if one_hot {
+                    let width = self.column_width(id);
+                    into.extend((0..width).map(|i| if i == index { 1. } else { 0. }));
+                } else {
+                    into.push(index as f32);
+                }
+            }
+        }
+    }
+}
+
+/// How a missing value in a given column should be filled in, or whether its whole record should
+/// be dropped, used by [`DataLoader::with_imputer`].
+pub enum ImputeStrategy {
+    /// Fills with a fixed value, e.g. one computed on a training set and reapplied here.
+    Constant(f32),
+    /// Fills with the mean of the column's non-missing values.
+    Mean,
+    /// Fills with the median of the column's non-missing values.
+    Median,
+    /// Drops the whole record.
+    Drop,
+}
+
+/// A configurable missing-value policy: per-column sentinel tokens recognized as missing (in
+/// addition to the empty field and `NA`/`NaN`, which are missing in every column), and a
+/// per-column [`ImputeStrategy`] to fill or drop them.
+///
+/// `Mean` and `Median` require a first pass over the source to accumulate column statistics before
+/// the fill values can be computed; those values are then retained on the `Imputer` so they can be
+/// queried and reapplied to a held-out set via `Constant`.
+#[derive(Default)]
+pub struct Imputer {
+    sentinels: BTreeMap<usize, Vec<String>>,
+    strategies: BTreeMap<usize, ImputeStrategy>,
+    fill_values: BTreeMap<usize, f32>,
+}
+
+impl Imputer {
+    /// Creates an empty imputer. Columns with no registered strategy still panic on a missing
+    /// value, matching the previous unconditional `record.unwrap()` behavior.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers additional tokens recognized as missing in column `id`, e.g. `&["?", "-"]`. The
+    /// empty field, `NA` and `NaN` (case-insensitive) are always recognized as missing, in every
+    /// column; registered tokens are matched exactly.
+    pub fn with_sentinels(mut self, id: usize, sentinels: &[&str]) -> Self {
+        self.sentinels
+            .entry(id)
+            .or_default()
+            .extend(sentinels.iter().map(|&token| token.to_owned()));
+        self
+    }
+
+    /// Registers the strategy used to fill (or drop) a missing value in column `id`.
+    pub fn with_strategy(mut self, id: usize, strategy: ImputeStrategy) -> Self {
+        self.strategies.insert(id, strategy);
+        self
+    }
+
+    /// Returns the fill value computed for column `id` during the last load, e.g. to reuse it as a
+    /// `Constant` strategy on a held-out set.
+    pub fn fill_value(&self, id: usize) -> Option<f32> {
+        self.fill_values.get(&id).copied()
+    }
+
+    fn is_missing(&self, id: usize, field: &str) -> bool {
+        field.is_empty()
+            || field.eq_ignore_ascii_case("na")
+            || field.eq_ignore_ascii_case("nan")
+            || self
+                .sentinels
+                .get(&id)
+                .is_some_and(|tokens| tokens.iter().any(|token| token == field))
+    }
+
+    /// First pass: accumulates running statistics for every `Mean`/`Median` column over its
+    /// non-missing values across `records`, storing the resulting fill values, along with every
+    /// `Constant` column's configured value.
+    fn compute_fill_values(&mut self, records: &[StringRecord]) {
+        let mut sums: BTreeMap<usize, (f32, usize)> = BTreeMap::new();
+        let mut samples: BTreeMap<usize, Vec<f32>> = BTreeMap::new();
+
+        for record in records {
+            for (id, field) in record.iter().enumerate() {
+                if self.is_missing(id, field) {
+                    continue;
+                }
+
+                match self.strategies.get(&id) {
+                    Some(ImputeStrategy::Mean) => {
+                        if let Ok(value) = field.parse::<f32>() {
+                            let entry = sums.entry(id).or_insert((0., 0));
+                            entry.0 += value;
+                            entry.1 += 1;
+                        }
+                    }
+                    Some(ImputeStrategy::Median) => {
+                        if let Ok(value) = field.parse::<f32>() {
+                            samples.entry(id).or_default().push(value);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        for (id, (sum, count)) in sums {
+            self.fill_values.insert(id, sum / count as f32);
+        }
+
+        for (id, mut column) in samples {
+            column.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let mid = column.len() / 2;
+            let median = if column.len() % 2 == 0 {
+                (column[mid - 1] + column[mid]) / 2.
+            } else {
+                column[mid]
+            };
+            self.fill_values.insert(id, median);
+        }
+
+        for (&id, strategy) in &self.strategies {
+            if let ImputeStrategy::Constant(value) = strategy {
+                self.fill_values.insert(id, *value);
+            }
+        }
+    }
+
+    /// Second pass: resolves a single field of column `id`, returning its filled value, or `None`
+    /// if the record it belongs to must be dropped.
+    ///
+    /// # Panics
+    ///
+    /// If the field is missing and no strategy was registered for its column, or if a non-missing
+    /// field cannot be parsed as a number.
+    fn resolve(&self, id: usize, field: &str) -> Option<f32> {
+        if !self.is_missing(id, field) {
+            return Some(field.parse().unwrap());
+        }
+
+        match self.strategies.get(&id) {
+            Some(ImputeStrategy::Drop) => None,
+            Some(_) => Some(
+                self.fill_values
+                    .get(&id)
+                    .copied()
+                    .unwrap_or_else(|| panic!("error: no fill value computed for column {id}.")),
+            ),
+            None => panic!("error: missing value in column {id} has no configured impute strategy."),
+        }
+    }
+}
+
 /// A collection of uniquely owned unlabeled records.
 ///
 /// See also [*data*](index.html#data).
@@ -88,6 +411,24 @@ impl <D: RemoveAxis> Dataset<D> {
         KFold::new(self.records.view(), k)
     }
 
+    /// Constructs a Leave-P-Out cross-validator from the dataset.
+    ///
+    /// Yields every split where a distinct size-`p` subset of rows is held out as the test set,
+    /// i.e. `C(len(), p)` folds in total - only practical for small datasets.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is `0` or greater than the number of records.
+    pub fn leave_p_out(&self, p: usize) -> LeavePOut<D> {
+        LeavePOut::new(self.records.view(), p)
+    }
+
+    /// Constructs a Leave-One-Out cross-validator from the dataset, the `p == 1` special case of
+    /// [`Dataset::leave_p_out`].
+    pub fn leave_one_out(&self) -> LeavePOut<D> {
+        self.leave_p_out(1)
+    }
+
     /// Divides the dataset into batches of size `batch_size`.
     ///
     /// # Arguments
@@ -162,6 +503,8 @@ impl <D: RemoveAxis> Dataset<D> {
 /// Configurable data loader.
 pub struct DataLoader {
     r_builder: ReaderBuilder,
+    schema: Option<Schema>,
+    imputer: Option<Imputer>,
 }
 
 impl DataLoader {
@@ -178,6 +521,40 @@ impl DataLoader {
         LabeledDataLoader::new(self, labels)
     }
 
+    /// Configures a per-column [`Schema`] driving conversion for [`DataLoader::from_csv_with_schema`]
+    /// / [`DataLoader::from_reader_with_schema`], instead of parsing every field as a plain `f32`.
+    ///
+    /// # Arguments
+    ///
+    /// `schema` - the per-column conversion rules to apply.
+    pub fn with_schema(&mut self, schema: Schema) -> &mut Self {
+        self.schema = Some(schema);
+
+        self
+    }
+
+    /// Returns the configured schema, including any categorical mappings discovered so far.
+    pub fn schema(&self) -> Option<&Schema> {
+        self.schema.as_ref()
+    }
+
+    /// Configures the missing-value policy driving [`DataLoader::from_csv_with_imputer`] /
+    /// [`DataLoader::from_reader_with_imputer`], instead of panicking on a blank or sentinel field.
+    ///
+    /// # Arguments
+    ///
+    /// `imputer` - the per-column sentinel tokens and fill/drop strategies to apply.
+    pub fn with_imputer(&mut self, imputer: Imputer) -> &mut Self {
+        self.imputer = Some(imputer);
+
+        self
+    }
+
+    /// Returns the configured imputer, including any fill values computed so far.
+    pub fn imputer(&self) -> Option<&Imputer> {
+        self.imputer.as_ref()
+    }
+
     /// Configures the loader so that it parses the first row. To be used in the absence of an
     /// header row, as in most datasets the first row usually contains the columns' identifiers.
     pub fn without_headers(&mut self) -> &mut Self {
@@ -299,6 +676,249 @@ impl DataLoader {
 
         Dataset::new(Array::from_shape_vec(stacked_shape(rows, shape), records).unwrap())
     }
+
+    /// Builds a data collection from an already-opened Arrow record batch reader, copying every
+    /// column of every record batch, in order, directly into the backing `Array` without ever
+    /// going through a `StringRecord`. Columns cannot be selected: a record is all of a batch's
+    /// columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - iterator of Arrow record batches, e.g. a Parquet or Arrow IPC file reader.
+    /// * `shape` - shape of a single record.
+    ///
+    /// # Panics
+    ///
+    /// If a record batch fails to read, if a column holds a non-numeric type, or if `shape`
+    /// generates an empty record.
+    pub fn from_arrow_reader<I, S>(&mut self, reader: I, shape: S) -> Dataset<<S::Dim as Dimension>::Larger>
+    where
+        I: Iterator<Item = arrow2::error::Result<Chunk<Box<dyn ArrowArray>>>>,
+        S: IntoDimension,
+    {
+        let shape = shape.into_dimension();
+        if shape.size() == 0 {
+            panic!("error: cannot handle empty records.")
+        }
+
+        let mut records = Vec::new();
+        let mut rows = 0;
+        for chunk in reader {
+            let chunk = chunk.unwrap();
+            rows += chunk.len();
+            chunk_rows_into(&chunk, &mut records);
+        }
+
+        Dataset::new(Array::from_shape_vec(stacked_shape(rows, shape), records).unwrap())
+    }
+
+    /// Builds a data collection from an Arrow IPC file.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    /// * `shape` - shape of a single record.
+    ///
+    /// # Panics
+    ///
+    /// In the case of errors during I/O, if a column holds a non-numeric type, or if `shape`
+    /// generates an empty record.
+    pub fn from_arrow<S>(&mut self, src: &str, shape: S) -> Dataset<<S::Dim as Dimension>::Larger>
+    where
+        S: IntoDimension,
+    {
+        let mut file = File::open(src).unwrap();
+        let metadata = ipc::read::read_file_metadata(&mut file).unwrap();
+        let reader = ipc::read::FileReader::new(file, metadata, None, None);
+
+        self.from_arrow_reader(reader, shape)
+    }
+
+    /// Builds a data collection from a Parquet file.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    /// * `shape` - shape of a single record.
+    ///
+    /// # Panics
+    ///
+    /// In the case of errors during I/O, if a column holds a non-numeric type, or if `shape`
+    /// generates an empty record.
+    pub fn from_parquet<S>(&mut self, src: &str, shape: S) -> Dataset<<S::Dim as Dimension>::Larger>
+    where
+        S: IntoDimension,
+    {
+        let mut file = File::open(src).unwrap();
+        let metadata = parquet::read::read_metadata(&mut file).unwrap();
+        let schema = parquet::read::infer_schema(&metadata).unwrap();
+        let reader = parquet::read::FileReader::new(file, metadata.row_groups, schema, None, None, None);
+
+        self.from_arrow_reader(reader, shape)
+    }
+
+    /// Builds a data collection by loading the content of the specified `.csv` file, converting
+    /// each column through the [`Schema`] configured via [`DataLoader::with_schema`] instead of
+    /// parsing every field as a plain `f32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    ///
+    /// # Panics
+    ///
+    /// In the case of I/O or parsing errors, or if no schema was configured via `with_schema`.
+    pub fn from_csv_with_schema(&mut self, src: &str) -> Dataset<Ix2> {
+        self.from_reader_with_schema(File::open(src).unwrap())
+    }
+
+    /// Builds a data collection by loading the content of the specified source reader, converting
+    /// each column through the [`Schema`] configured via [`DataLoader::with_schema`] instead of
+    /// parsing every field as a plain `f32`.
+    ///
+    /// Categorical columns are discovered in a first pass over the buffered records so that their
+    /// (possibly one-hot) width is known before the final record width is computed, then every
+    /// record is converted in a second pass.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - reader from which to load the data.
+    ///
+    /// # Panics
+    ///
+    /// In the event of a deserialization error, or if no schema was configured via `with_schema`.
+    pub fn from_reader_with_schema<R>(&mut self, src: R) -> Dataset<Ix2>
+    where
+        R: Read,
+    {
+        let raw_records: Vec<StringRecord> = self
+            .r_builder
+            .from_reader(src)
+            .records()
+            .map(|record| record.unwrap())
+            .collect();
+
+        let schema = self
+            .schema
+            .as_mut()
+            .expect("error: no schema configured; call `with_schema` first.");
+
+        for record in &raw_records {
+            for (id, field) in record.iter().enumerate() {
+                if let Some(Conversion::Categorical { .. }) = schema.conversions.get(&id) {
+                    schema.category_index(id, field);
+                }
+            }
+        }
+
+        let rows = raw_records.len();
+        let width = schema.record_width(raw_records.first().map_or(0, StringRecord::len));
+
+        let mut data = Vec::with_capacity(rows * width);
+        for record in &raw_records {
+            for (id, field) in record.iter().enumerate() {
+                schema.convert(id, field, &mut data);
+            }
+        }
+
+        Dataset::new(Array::from_shape_vec((rows, width), data).unwrap())
+    }
+
+    /// Lazily loads `batch_size`-sized batches from `src`, applying the previously supplied
+    /// configuration.
+    ///
+    /// Unlike [`DataLoader::from_reader`], which materializes the whole source into a single
+    /// `Array` before it can be batched, the returned [`Stream`] only ever holds one batch (plus an
+    /// optional shuffle buffer) in memory, making datasets larger than RAM tractable.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - reader from which to load the data.
+    /// * `shape` - shape of a single record.
+    /// * `batch_size` - size of a single batch.
+    pub fn stream<R, S>(&mut self, src: R, shape: S, batch_size: usize) -> Stream<R, S::Dim>
+    where
+        R: Read,
+        S: IntoDimension,
+    {
+        Stream::new(self.r_builder.from_reader(src), shape.into_dimension(), batch_size)
+    }
+
+    /// Builds a data collection by loading the content of the specified `.csv` file, applying the
+    /// missing-value policy configured via [`DataLoader::with_imputer`] instead of panicking on a
+    /// blank or sentinel field.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    /// * `shape` - shape of a single record.
+    ///
+    /// # Panics
+    ///
+    /// In the case of I/O or parsing errors, or if no imputer was configured via `with_imputer`.
+    pub fn from_csv_with_imputer<S>(&mut self, src: &str, shape: S) -> Dataset<<S::Dim as Dimension>::Larger>
+    where
+        S: IntoDimension,
+    {
+        self.from_reader_with_imputer(File::open(src).unwrap(), shape)
+    }
+
+    /// Builds a data collection by loading the content of the specified source reader, applying
+    /// the missing-value policy configured via [`DataLoader::with_imputer`] instead of panicking on
+    /// a blank or sentinel field.
+    ///
+    /// Requires a first pass over the buffered records to compute any `Mean`/`Median` fill values
+    /// before the final record-by-record fill/drop pass builds the `Array`.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - reader from which to load the data.
+    /// * `shape` - shape of a single record.
+    ///
+    /// # Panics
+    ///
+    /// In the event of a deserialization error, a missing value with no configured strategy, or if
+    /// no imputer was configured via `with_imputer`.
+    pub fn from_reader_with_imputer<R, S>(&mut self, src: R, shape: S) -> Dataset<<S::Dim as Dimension>::Larger>
+    where
+        R: Read,
+        S: IntoDimension,
+    {
+        let shape = shape.into_dimension();
+        if shape.size() == 0 {
+            panic!("error: cannot handle empty records.")
+        }
+
+        let raw_records: Vec<StringRecord> = self
+            .r_builder
+            .from_reader(src)
+            .records()
+            .map(|record| record.unwrap())
+            .collect();
+
+        let imputer = self
+            .imputer
+            .as_mut()
+            .expect("error: no imputer configured; call `with_imputer` first.");
+        imputer.compute_fill_values(&raw_records);
+
+        let mut records = Vec::new();
+        let mut rows = 0;
+        'record: for record in &raw_records {
+            let mut row = Vec::with_capacity(shape.size());
+            for (id, field) in record.iter().enumerate() {
+                match imputer.resolve(id, field) {
+                    Some(value) => row.push(value),
+                    None => continue 'record,
+                }
+            }
+
+            records.extend(row);
+            rows += 1;
+        }
+
+        Dataset::new(Array::from_shape_vec(stacked_shape(rows, shape), records).unwrap())
+    }
 }
 
 impl Default for DataLoader {
@@ -309,6 +929,8 @@ impl Default for DataLoader {
     fn default() -> Self {
         Self {
             r_builder: ReaderBuilder::new(),
+            schema: None,
+            imputer: None,
         }
     }
 }
@@ -317,6 +939,7 @@ impl Default for DataLoader {
 pub struct LabeledDataLoader {
     r_bulder: ReaderBuilder,
     labels: Vec<usize>,
+    imputer: Option<Imputer>,
 }
 
 impl LabeledDataLoader {
@@ -353,6 +976,7 @@ impl LabeledDataLoader {
         Self {
             r_bulder: builder.r_builder,
             labels,
+            imputer: None,
         }
     }
 
@@ -375,6 +999,25 @@ impl LabeledDataLoader {
         self
     }
 
+    /// Configures the missing-value policy driving [`LabeledDataLoader::from_csv_with_imputer`] /
+    /// [`LabeledDataLoader::from_reader_with_imputer`], instead of panicking on a blank or sentinel
+    /// field. Column indices refer to the original CSV columns, the same convention used by
+    /// [`DataLoader::with_labels`].
+    ///
+    /// # Arguments
+    ///
+    /// `imputer` - the per-column sentinel tokens and fill/drop strategies to apply.
+    pub fn with_imputer(&mut self, imputer: Imputer) -> &mut Self {
+        self.imputer = Some(imputer);
+
+        self
+    }
+
+    /// Returns the configured imputer, including any fill values computed so far.
+    pub fn imputer(&self) -> Option<&Imputer> {
+        self.imputer.as_ref()
+    }
+
     /// Builds a labeled data collection by loading the content of the specified `.csv` file
     /// applying the previously supplied configuration.
     ///
@@ -509,6 +1152,260 @@ impl LabeledDataLoader {
             Array::from_shape_vec(stacked_shape(rows, label_shape), labels).unwrap(),
         )
     }
+
+    /// Builds a labeled data collection from an already-opened Arrow record batch reader. The
+    /// columns previously passed to [`DataLoader::with_labels`] select which Arrow columns become
+    /// the label tensor; every other column becomes part of the record tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - iterator of Arrow record batches, e.g. a Parquet or Arrow IPC file reader.
+    /// * `record_shape` - shape of a single record.
+    /// * `label_shape` - shape of a single label.
+    ///
+    /// # Panics
+    ///
+    /// If a record batch fails to read, if a column holds a non-numeric type, or if
+    /// `record_shape`/`label_shape` generates an empty record/label.
+    pub fn from_arrow_reader<I, S1, S2>(
+        &mut self,
+        reader: I,
+        record_shape: S1,
+        label_shape: S2,
+    ) -> LabeledDataset<<S1::Dim as Dimension>::Larger, <S2::Dim as Dimension>::Larger>
+    where
+        I: Iterator<Item = arrow2::error::Result<Chunk<Box<dyn ArrowArray>>>>,
+        S1: IntoDimension,
+        S2: IntoDimension,
+    {
+        let record_shape = record_shape.into_dimension();
+        let label_shape = label_shape.into_dimension();
+        if record_shape.size() == 0 || label_shape.size() == 0 {
+            panic!("error: cannot handle empty records")
+        }
+
+        let mut records = Vec::new();
+        let mut labels = Vec::new();
+        let mut rows = 0;
+        for chunk in reader {
+            let chunk = chunk.unwrap();
+            let columns: Vec<Vec<f32>> = chunk
+                .arrays()
+                .iter()
+                .map(|column| column_to_f32(column.as_ref()))
+                .collect();
+            rows += chunk.len();
+
+            for row in 0..chunk.len() {
+                for (id, column) in columns.iter().enumerate() {
+                    match self.labels.binary_search(&id) {
+                        Ok(_) => labels.push(column[row]),
+                        Err(_) => records.push(column[row]),
+                    }
+                }
+            }
+        }
+
+        LabeledDataset::new(
+            Array::from_shape_vec(stacked_shape(rows, record_shape), records).unwrap(),
+            Array::from_shape_vec(stacked_shape(rows, label_shape), labels).unwrap(),
+        )
+    }
+
+    /// Builds a labeled data collection from an Arrow IPC file.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    /// * `record_shape` - shape of a single record.
+    /// * `label_shape` - shape of a single label.
+    ///
+    /// # Panics
+    ///
+    /// In the case of errors during I/O, if a column holds a non-numeric type, or if
+    /// `record_shape`/`label_shape` generates an empty record/label.
+    pub fn from_arrow<S1, S2>(
+        &mut self,
+        src: &str,
+        record_shape: S1,
+        label_shape: S2,
+    ) -> LabeledDataset<<S1::Dim as Dimension>::Larger, <S2::Dim as Dimension>::Larger>
+    where
+        S1: IntoDimension,
+        S2: IntoDimension,
+    {
+        let mut file = File::open(src).unwrap();
+        let metadata = ipc::read::read_file_metadata(&mut file).unwrap();
+        let reader = ipc::read::FileReader::new(file, metadata, None, None);
+
+        self.from_arrow_reader(reader, record_shape, label_shape)
+    }
+
+    /// Builds a labeled data collection from a Parquet file.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    /// * `record_shape` - shape of a single record.
+    /// * `label_shape` - shape of a single label.
+    ///
+    /// # Panics
+    ///
+    /// In the case of errors during I/O, if a column holds a non-numeric type, or if
+    /// `record_shape`/`label_shape` generates an empty record/label.
+    pub fn from_parquet<S1, S2>(
+        &mut self,
+        src: &str,
+        record_shape: S1,
+        label_shape: S2,
+    ) -> LabeledDataset<<S1::Dim as Dimension>::Larger, <S2::Dim as Dimension>::Larger>
+    where
+        S1: IntoDimension,
+        S2: IntoDimension,
+    {
+        let mut file = File::open(src).unwrap();
+        let metadata = parquet::read::read_metadata(&mut file).unwrap();
+        let schema = parquet::read::infer_schema(&metadata).unwrap();
+        let reader = parquet::read::FileReader::new(file, metadata.row_groups, schema, None, None, None);
+
+        self.from_arrow_reader(reader, record_shape, label_shape)
+    }
+
+    /// Lazily loads `batch_size`-sized batches from `src`, applying the previously supplied
+    /// configuration.
+    ///
+    /// Unlike [`LabeledDataLoader::from_reader`], which materializes the whole source into a single
+    /// pair of `Array`s before it can be batched, the returned [`LabeledStream`] only ever holds one
+    /// batch (plus an optional shuffle buffer) in memory, making datasets larger than RAM tractable.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - reader from which to load the data.
+    /// * `record_shape` - shape of a single record.
+    /// * `label_shape` - shape of a single label.
+    /// * `batch_size` - size of a single batch.
+    pub fn stream<R, S1, S2>(
+        &mut self,
+        src: R,
+        record_shape: S1,
+        label_shape: S2,
+        batch_size: usize,
+    ) -> LabeledStream<R, S1::Dim, S2::Dim>
+    where
+        R: Read,
+        S1: IntoDimension,
+        S2: IntoDimension,
+    {
+        LabeledStream::new(
+            self.r_bulder.from_reader(src),
+            record_shape.into_dimension(),
+            label_shape.into_dimension(),
+            batch_size,
+            self.labels.clone(),
+        )
+    }
+
+    /// Builds a labeled data collection by loading the content of the specified `.csv` file,
+    /// applying the missing-value policy configured via [`LabeledDataLoader::with_imputer`] instead
+    /// of panicking on a blank or sentinel field.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - path of the source file.
+    /// * `record_shape` - shape of a single record.
+    /// * `label_shape` - shape of a single label.
+    ///
+    /// # Panics
+    ///
+    /// In the case of I/O or parsing errors, or if no imputer was configured via `with_imputer`.
+    pub fn from_csv_with_imputer<S1, S2>(
+        &mut self,
+        src: &str,
+        record_shape: S1,
+        label_shape: S2,
+    ) -> LabeledDataset<<S1::Dim as Dimension>::Larger, <S2::Dim as Dimension>::Larger>
+    where
+        S1: IntoDimension,
+        S2: IntoDimension,
+    {
+        self.from_reader_with_imputer(File::open(src).unwrap(), record_shape, label_shape)
+    }
+
+    /// Builds a labeled data collection by loading the content of the specified source reader,
+    /// applying the missing-value policy configured via [`LabeledDataLoader::with_imputer`] instead
+    /// of panicking on a blank or sentinel field.
+    ///
+    /// Requires a first pass over the buffered records to compute any `Mean`/`Median` fill values
+    /// before the final record-by-record fill/drop pass builds the records and labels `Array`s.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - reader from which to load the data.
+    /// * `record_shape` - shape of a single record.
+    /// * `label_shape` - shape of a single label.
+    ///
+    /// # Panics
+    ///
+    /// In the event of a deserialization error, a missing value with no configured strategy, or if
+    /// no imputer was configured via `with_imputer`.
+    pub fn from_reader_with_imputer<R, S1, S2>(
+        &mut self,
+        src: R,
+        record_shape: S1,
+        label_shape: S2,
+    ) -> LabeledDataset<<S1::Dim as Dimension>::Larger, <S2::Dim as Dimension>::Larger>
+    where
+        R: Read,
+        S1: IntoDimension,
+        S2: IntoDimension,
+    {
+        let record_shape = record_shape.into_dimension();
+        let label_shape = label_shape.into_dimension();
+        if record_shape.size() == 0 || label_shape.size() == 0 {
+            panic!("error: cannot handle empty records")
+        }
+
+        let raw_records: Vec<StringRecord> = self
+            .r_bulder
+            .from_reader(src)
+            .records()
+            .map(|record| record.unwrap())
+            .collect();
+
+        let imputer = self
+            .imputer
+            .as_mut()
+            .expect("error: no imputer configured; call `with_imputer` first.");
+        imputer.compute_fill_values(&raw_records);
+
+        let mut records = Vec::new();
+        let mut labels = Vec::new();
+        let mut rows = 0;
+        'record: for record in &raw_records {
+            let mut record_row = Vec::with_capacity(record_shape.size());
+            let mut label_row = Vec::with_capacity(label_shape.size());
+            for (id, field) in record.iter().enumerate() {
+                let value = match imputer.resolve(id, field) {
+                    Some(value) => value,
+                    None => continue 'record,
+                };
+
+                match self.labels.binary_search(&id) {
+                    Ok(_) => label_row.push(value),
+                    Err(_) => record_row.push(value),
+                }
+            }
+
+            records.extend(record_row);
+            labels.extend(label_row);
+            rows += 1;
+        }
+
+        LabeledDataset::new(
+            Array::from_shape_vec(stacked_shape(rows, record_shape), records).unwrap(),
+            Array::from_shape_vec(stacked_shape(rows, label_shape), labels).unwrap(),
+        )
+    }
 }
 
 /// A collection of uniquely owned *labeled* records.
@@ -575,6 +1472,24 @@ impl<D1: RemoveAxis, D2: RemoveAxis> LabeledDataset<D1, D2> {
         LabeledKFold::new(self.records.view(), self.labels.view(), k)
     }
 
+    /// Constructs a Leave-P-Out cross-validator from the labeled dataset.
+    ///
+    /// Yields every split where a distinct size-`p` subset of rows is held out as the test set,
+    /// i.e. `C(len(), p)` folds in total - only practical for small datasets.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is `0` or greater than the number of records.
+    pub fn leave_p_out(&self, p: usize) -> LabeledLeavePOut<D1, D2> {
+        LabeledLeavePOut::new(self.records.view(), self.labels.view(), p)
+    }
+
+    /// Constructs a Leave-One-Out cross-validator from the labeled dataset, the `p == 1` special
+    /// case of [`LabeledDataset::leave_p_out`].
+    pub fn leave_one_out(&self) -> LabeledLeavePOut<D1, D2> {
+        self.leave_p_out(1)
+    }
+
     /// Divides the labeled dataset into batches of size `batch_size`.
     ///
     /// # Arguments
@@ -657,64 +1572,416 @@ impl<D1: RemoveAxis, D2: RemoveAxis> LabeledDataset<D1, D2> {
 
         self
     }
+
+    /// Constructs a K-Fold iterator that distributes records of each class as evenly as possible
+    /// across folds.
+    ///
+    /// Plain [`LabeledDataset::kfold`] splits into `k` consecutive contiguous folds, which gives
+    /// badly imbalanced folds when labels are sorted or class-skewed. This groups record indices
+    /// by class key (the argmax for one-hot labels, or the scalar value itself for 1-D labels),
+    /// then round-robins each group's indices across the `k` folds so every fold's class
+    /// proportions approximate those of the whole dataset.
+    ///
+    /// # Arguments
+    ///
+    /// `k` - number of folds to perform.
+    ///
+    /// # Panics
+    ///
+    /// If `k < 2`.
+    pub fn stratified_kfold(&self, k: usize) -> LabeledKFold<D1, D2> {
+        self.stratified_kfold_with_seed(k, random())
+    }
+
+    /// Constructs a stratified K-Fold iterator.
+    ///
+    /// This version allows for a seed to be specified for results reproducibility.
+    pub fn stratified_kfold_with_seed(&self, k: usize, seed: u64) -> LabeledKFold<D1, D2> {
+        let mut by_label: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+        for (id, row) in self.labels.outer_iter().enumerate() {
+            by_label.entry(class_key(&row)).or_default().push(id);
+        }
+
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut folds: Vec<Vec<usize>> = vec![Vec::new(); k];
+        for mut ids in by_label.into_values() {
+            let offset = rng.gen_range(0..k);
+            for (i, id) in ids.drain(..).enumerate() {
+                folds[(i + offset) % k].push(id);
+            }
+        }
+
+        LabeledKFold::from_folds(self.records.view(), self.labels.view(), folds)
+    }
+
+    /// Constructs a K-Fold iterator that guarantees all records sharing a group id land in the
+    /// same fold, preventing leakage of a group across the train/validation split.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - number of folds to perform.
+    /// * `group_ids` - a group id for each record, indexed the same way as the dataset's records.
+    ///
+    /// # Panics
+    ///
+    /// If `k < 2`, or if `group_ids.len()` does not match the number of records.
+    pub fn grouped_kfold(&self, k: usize, group_ids: &[usize]) -> LabeledKFold<D1, D2> {
+        self.grouped_kfold_with_seed(k, group_ids, random())
+    }
+
+    /// Constructs a grouped K-Fold iterator.
+    ///
+    /// This version allows for a seed to be specified for results reproducibility.
+    pub fn grouped_kfold_with_seed(&self, k: usize, group_ids: &[usize], seed: u64) -> LabeledKFold<D1, D2> {
+        assert_eq!(group_ids.len(), self.len());
+
+        let mut by_group: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (id, group) in group_ids.iter().enumerate() {
+            by_group.entry(*group).or_default().push(id);
+        }
+
+        let mut groups: Vec<Vec<usize>> = by_group.into_values().collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        for i in (1..groups.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            groups.swap(i, j);
+        }
+
+        // Greedily assign each whole group, in shuffled order, to the fold with the fewest records
+        // so far, which keeps fold sizes close to balanced without ever splitting a group across
+        // folds. Unlike `grouped_folds`, group order here is controlled by `seed` rather than
+        // first-seen order, so it must not be re-sorted afterwards.
+        let mut folds: Vec<Vec<usize>> = vec![Vec::new(); k];
+        for mut ids in groups {
+            let smallest = folds
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, fold)| fold.len())
+                .map(|(i, _)| i)
+                .unwrap();
+            folds[smallest].append(&mut ids);
+        }
+
+        LabeledKFold::from_folds(self.records.view(), self.labels.view(), folds)
+    }
 }
 
 /// Iterator over batches of unlabeled data.
-// 这里'a表示生命周期参数，生命周期参数通常用于确保引用的有效性。在这个情况下，'a 生命周期参数表示 Batch 结构体中的
-// iter 字段引用的数据的生命周期必须至少与 'a 生命周期一样长，以确保引用的数据在使用期间有效。
+///
+/// Rows are addressed through `order`, so that [`Batch::shuffle`] can route batching through a
+/// permutation; but as long as `order` is still the identity permutation `shuffle` left it
+/// (nothing has shuffled it), consecutive batches are contiguous ranges of `source`, so `next`
+/// slices them out as a zero-copy [`CowArray`] view instead of gathering/copying row by row. Only
+/// an actual [`Batch::shuffle`] call forces the copying, fancy-indexed path.
 pub struct Batch<'a, D> {
-    iter: AxisChunksIter<'a, f32, D>,
+    source: ArrayView<'a, f32, D>,
+    order: Vec<usize>,
+    batch_size: usize,
+    position: usize,
+    shuffled: bool,
 }
 
 impl<'a, D: RemoveAxis> Batch<'a, D> {
-    /**
-    *这个生命周期参数的作用是告诉 Rust 编译器，在函数内部，source 参数引用的数据在整个函数的执行过程中必须保持有效。这是 Rust 借用检查系统的一部分，用于确保引用的有效性和安全性。
-
-    具体来说，'a 生命周期参数告诉编译器，source 参数引用的数据的生命周期不短于 'a 生命周期。这意味着在函数内部，source 参数引用的数据必须保持有效，直到 'a 生命周期结束。这可以防止在函数内部使用已经失效的引用，确保代码的正确性和安全性。
-
-    在这个函数中，'a 生命周期参数允许你创建一个 Batch 结构体，并将其 iter 字段初始化为一个 AxisChunksIter 的实例，其中包含了 'a 生命周期，以确保在 Batch 实例中使用的 source 数据在 Batch 实例的生命周期内有效。
-    */
     fn new(source: &'a Array<f32, D>, size: usize) -> Self {
+        let source = source.view();
+        let order = (0..source.len_of(Axis(0))).collect();
+
         Self {
-            iter: source.axis_chunks_iter(Axis(0), size),
+            source,
+            order,
+            batch_size: size,
+            position: 0,
+            shuffled: false,
         }
     }
 
     /// Drops the last incomplete batch, if the dataset size is not divisible by the batch size.
     pub fn drop_last(mut self) -> Self {
-        let mut current = self.iter.clone();
-
-        //Some() 是一个 Rust 中的 Option 枚举的成员之一，用于表示某个值存在的情况。
-        // Option 通常用于处理可能为空（或不存在）的值，它有两个成员：Some(T) 和 None。
-        // Some(T) 表示一个包含具体值 T 的情况，也就是某个值存在。
-        // None 表示值不存在或为空的情况。
-        // if let Some(next) = current.next() 和 if let Some(last) = current.last()
-        // 这两行代码使用了 if let 表达式来匹配 current.next() 和 current.last() 的返回值是否为 Some。
-        // 如果是 Some，则将其中的值绑定到 next 和 last 变量中。
-        if let Some(next) = current.next() {
-            if let Some(last) = current.last() {
-                if next.len_of(Axis(0)) != last.len_of(Axis(0)) {
-                    self.iter = self.iter.dropping_back(1);
+        let remainder = self.order.len() % self.batch_size;
+        self.order.truncate(self.order.len() - remainder);
+
+        self
+    }
+
+    /// Shuffles the order in which rows are grouped into batches.
+    ///
+    /// This version allows for a seed to be specified for results reproducibility. Must be called
+    /// before the first call to `next`.
+    pub fn shuffle(mut self, seed: u64) -> Self {
+        self.order = fisher_yates_permutation(self.order.len(), seed);
+        self.shuffled = true;
+
+        self
+    }
+}
+
+impl<'a, D: RemoveAxis> Iterator for Batch<'a, D> {
+    type Item = CowArray<'a, f32, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position >= self.order.len() {
+            return None;
+        }
+
+        let start = self.position;
+        let stop = self.order.len().min(self.position + self.batch_size);
+        self.position = stop;
+
+        if self.shuffled {
+            Some(CowArray::from(self.source.select(Axis(0), &self.order[start..stop])))
+        } else {
+            Some(CowArray::from(self.source.slice_axis(Axis(0), Slice::from(start..stop))))
+        }
+    }
+}
+
+/// Iterator over lazily-loaded batches of unlabeled data, built by [`DataLoader::stream`].
+///
+/// Reads records from the underlying CSV reader on demand, one batch at a time, rather than
+/// materializing the whole source up front like [`Dataset::batch`] requires.
+pub struct Stream<R, D> {
+    records: csv::DeserializeRecordsIntoIter<R, Vec<f32>>,
+    shape: D,
+    batch_size: usize,
+    drop_last: bool,
+    shuffle_buffer: Option<(usize, Vec<Vec<f32>>)>,
+    rng: StdRng,
+    exhausted: bool,
+}
+
+impl<R: Read, D: RemoveAxis> Stream<R, D> {
+    fn new(reader: csv::Reader<R>, shape: D, batch_size: usize) -> Self {
+        Self {
+            records: reader.into_deserialize(),
+            shape,
+            batch_size,
+            drop_last: false,
+            shuffle_buffer: None,
+            rng: StdRng::from_entropy(),
+            exhausted: false,
+        }
+    }
+
+    /// Drops the last incomplete batch, if the source size is not divisible by `batch_size`.
+    pub fn drop_last(mut self) -> Self {
+        self.drop_last = true;
+        self
+    }
+
+    /// Buffers `size` records and drains them in random order, giving streaming training an
+    /// approximate shuffle without ever materializing the whole source.
+    pub fn shuffle_buffer(mut self, size: usize) -> Self {
+        self.shuffle_buffer = Some((size, Vec::with_capacity(size)));
+        self
+    }
+
+    fn next_record(&mut self) -> Option<Vec<f32>> {
+        match &mut self.shuffle_buffer {
+            None => self.records.next().map(|record| record.unwrap()),
+            Some((capacity, buffer)) => {
+                while !self.exhausted && buffer.len() < *capacity {
+                    match self.records.next() {
+                        Some(record) => buffer.push(record.unwrap()),
+                        None => {
+                            self.exhausted = true;
+                            break;
+                        }
+                    }
+                }
+
+                if buffer.is_empty() {
+                    None
+                } else {
+                    let index = self.rng.gen_range(0..buffer.len());
+                    Some(buffer.swap_remove(index))
+                }
+            }
+        }
+    }
+}
+
+impl<R: Read, D: RemoveAxis> Iterator for Stream<R, D> {
+    type Item = Dataset<D::Larger>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut records = Vec::new();
+        let mut rows = 0;
+        for _ in 0..self.batch_size {
+            match self.next_record() {
+                Some(record) => {
+                    records.extend(record);
+                    rows += 1;
                 }
+                None => break,
             }
         }
 
+        if rows == 0 || (self.drop_last && rows < self.batch_size) {
+            return None;
+        }
+
+        Some(Dataset::new(
+            Array::from_shape_vec(stacked_shape(rows, self.shape.clone()), records).unwrap(),
+        ))
+    }
+}
+
+/// Iterator over lazily-loaded batches of labeled data, built by [`LabeledDataLoader::stream`].
+///
+/// Reads records from the underlying CSV reader on demand, one batch at a time, rather than
+/// materializing the whole source up front like [`LabeledDataset::batch`] requires.
+pub struct LabeledStream<R, D1, D2> {
+    records: csv::StringRecordsIntoIter<R>,
+    record_shape: D1,
+    label_shape: D2,
+    labels: Vec<usize>,
+    batch_size: usize,
+    drop_last: bool,
+    shuffle_buffer: Option<(usize, Vec<(Vec<f32>, Vec<f32>)>)>,
+    rng: StdRng,
+    exhausted: bool,
+}
+
+impl<R: Read, D1: RemoveAxis, D2: RemoveAxis> LabeledStream<R, D1, D2> {
+    fn new(
+        reader: csv::Reader<R>,
+        record_shape: D1,
+        label_shape: D2,
+        batch_size: usize,
+        labels: Vec<usize>,
+    ) -> Self {
+        Self {
+            records: reader.into_records(),
+            record_shape,
+            label_shape,
+            labels,
+            batch_size,
+            drop_last: false,
+            shuffle_buffer: None,
+            rng: StdRng::from_entropy(),
+            exhausted: false,
+        }
+    }
+
+    /// Drops the last incomplete batch, if the source size is not divisible by `batch_size`.
+    pub fn drop_last(mut self) -> Self {
+        self.drop_last = true;
+        self
+    }
+
+    /// Buffers `size` records and drains them in random order, giving streaming training an
+    /// approximate shuffle without ever materializing the whole source.
+    pub fn shuffle_buffer(mut self, size: usize) -> Self {
+        self.shuffle_buffer = Some((size, Vec::with_capacity(size)));
         self
     }
+
+    fn split(&self, record: StringRecord) -> (Vec<f32>, Vec<f32>) {
+        let mut input = Vec::new();
+        let mut label = Vec::new();
+        for (id, value) in record.iter().enumerate() {
+            match self.labels.binary_search(&id) {
+                Ok(_) => label.push(value.parse().unwrap()),
+                Err(_) => input.push(value.parse().unwrap()),
+            }
+        }
+
+        (input, label)
+    }
+
+    fn next_record(&mut self) -> Option<(Vec<f32>, Vec<f32>)> {
+        match &mut self.shuffle_buffer {
+            None => self
+                .records
+                .next()
+                .map(|record| self.split(record.unwrap())),
+            Some((capacity, buffer)) => {
+                while !self.exhausted && buffer.len() < *capacity {
+                    match self.records.next() {
+                        Some(record) => {
+                            let record = self.split(record.unwrap());
+                            buffer.push(record);
+                        }
+                        None => {
+                            self.exhausted = true;
+                            break;
+                        }
+                    }
+                }
+
+                if buffer.is_empty() {
+                    None
+                } else {
+                    let index = self.rng.gen_range(0..buffer.len());
+                    Some(buffer.swap_remove(index))
+                }
+            }
+        }
+    }
 }
 
-impl<'a, D: RemoveAxis> Iterator for Batch<'a, D> {
-    type Item = <AxisChunksIter<'a, f32, D> as Iterator>::Item;
+impl<R: Read, D1: RemoveAxis, D2: RemoveAxis> Iterator for LabeledStream<R, D1, D2> {
+    type Item = LabeledDataset<D1::Larger, D2::Larger>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        let mut records = Vec::new();
+        let mut labels = Vec::new();
+        let mut rows = 0;
+        for _ in 0..self.batch_size {
+            match self.next_record() {
+                Some((record, label)) => {
+                    records.extend(record);
+                    labels.extend(label);
+                    rows += 1;
+                }
+                None => break,
+            }
+        }
+
+        if rows == 0 || (self.drop_last && rows < self.batch_size) {
+            return None;
+        }
+
+        Some(LabeledDataset::new(
+            Array::from_shape_vec(stacked_shape(rows, self.record_shape.clone()), records).unwrap(),
+            Array::from_shape_vec(stacked_shape(rows, self.label_shape.clone()), labels).unwrap(),
+        ))
     }
 }
 
+/// Produces a deterministic permutation of `0..n`, via a seeded Fisher-Yates shuffle.
+fn fisher_yates_permutation(n: usize, seed: u64) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..n).collect();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for i in (1..n).rev() {
+        let j = rng.gen_range(0..=i);
+        order.swap(i, j);
+    }
+
+    order
+}
+
+/// Splits `order` into `k` contiguous chunks of near-equal size (the last chunks may be smaller,
+/// or empty when `n` is not much larger than `k`), preserving whatever ordering `order` is
+/// already in.
+fn chunked_folds(order: &[usize], k: usize) -> Vec<Vec<usize>> {
+    let n = order.len();
+    let step = 1 + (n - 1) / k;
+
+    (0..k)
+        .map(|i| {
+            let start = n.min(step * i);
+            let stop = n.min(start + step);
+            order[start..stop].to_vec()
+        })
+        .collect()
+}
+
+#[derive(Clone)]
 struct SetKFold<'a, D> {
     source: ArrayView<'a, f32, D>,
-    step: usize,
-    axis_len: usize,
+    folds: Vec<Vec<usize>>,
 }
 
 impl<'a, D: RemoveAxis> SetKFold<'a, D> {
@@ -726,23 +1993,33 @@ impl<'a, D: RemoveAxis> SetKFold<'a, D> {
         let axis_len = source.len_of(Axis(0));
         debug_assert_ne!(axis_len, 0, "no record provided");
 
-        Self {
-            source,
-            step: 1 + (axis_len - 1) / k,
-            axis_len,
-        }
+        let order: Vec<usize> = (0..axis_len).collect();
+        let folds = chunked_folds(&order, k);
+
+        Self { source, folds }
+    }
+
+    /// Builds a `SetKFold` from precomputed per-fold index lists, rather than splitting the source
+    /// into `k` contiguous ranges. Used to drive stratified/grouped assignment through the same
+    /// `select(Axis(0), ..)` machinery as the plain contiguous split.
+    pub fn from_folds(source: ArrayView<'a, f32, D>, folds: Vec<Vec<usize>>) -> Self {
+        Self { source, folds }
     }
 
-    pub fn compute_fold(&mut self, i: usize) -> (Array<f32, D>, Array<f32, D>) {
-        let start = self.step * i;
-        let stop = self.axis_len.min(start + self.step);
+    pub fn k(&self) -> usize {
+        self.folds.len()
+    }
 
-        let train_ids: Vec<usize> = (0..start).chain(stop..self.axis_len).collect();
-        let test_ids: Vec<usize> = (start..stop).collect();
+    pub fn compute_fold(&self, i: usize) -> (Array<f32, D>, Array<f32, D>) {
+        let test_ids = &self.folds[i];
+        let in_test: std::collections::BTreeSet<usize> = test_ids.iter().copied().collect();
+        let train_ids: Vec<usize> = (0..self.source.len_of(Axis(0)))
+            .filter(|id| !in_test.contains(id))
+            .collect();
 
         (
             self.source.select(Axis(0), &train_ids),
-            self.source.select(Axis(0), &test_ids),
+            self.source.select(Axis(0), test_ids),
         )
     }
 }
@@ -770,6 +2047,143 @@ where
             k,
         }
     }
+
+    /// Shuffles the rows before splitting them into contiguous folds, so the dataset's natural
+    /// ordering doesn't leak into every fold.
+    ///
+    /// This version allows for a seed to be specified for results reproducibility. Must be called
+    /// before the first call to `next`. The same permutation is used for both `records` and
+    /// `labels`, so rows stay aligned.
+    pub fn shuffle(mut self, seed: u64) -> Self {
+        let order = fisher_yates_permutation(self.records.source.len_of(Axis(0)), seed);
+        let folds = chunked_folds(&order, self.k);
+
+        self.records = SetKFold::from_folds(self.records.source, folds.clone());
+        self.labels = SetKFold::from_folds(self.labels.source, folds);
+
+        self
+    }
+
+    /// Builds a `LabeledKFold` from precomputed per-fold index lists, e.g. as produced by
+    /// [`LabeledDataset::stratified_kfold`] or [`LabeledDataset::grouped_kfold`].
+    ///
+    /// # Panics
+    ///
+    /// If fewer than 2 folds are supplied.
+    fn from_folds(
+        records: ArrayView<'a, f32, D1>,
+        labels: ArrayView<'a, f32, D2>,
+        folds: Vec<Vec<usize>>,
+    ) -> Self {
+        assert_eq!(records.len_of(Axis(0)), labels.len_of(Axis(0)));
+        if folds.len() < 2 {
+            panic!("error: folds must be >= 2.");
+        }
+
+        Self {
+            records: SetKFold::from_folds(records, folds.clone()),
+            labels: SetKFold::from_folds(labels, folds),
+            iteration: 0,
+            k: 0,
+        }
+        .with_k()
+    }
+
+    fn with_k(mut self) -> Self {
+        self.k = self.records.k();
+        self
+    }
+
+    /// Builds a K-Fold iterator that keeps each fold's label distribution close to the whole
+    /// dataset's, rather than splitting into plain contiguous folds.
+    ///
+    /// Each row of `labels` is treated as a discrete class key: the argmax for one-hot labels, or
+    /// the scalar value itself for 1-D labels. Row indices are bucketed by class key, then each
+    /// class's indices are distributed round-robin across the `k` folds. A class with fewer than
+    /// `k` members simply leaves some folds without any of it.
+    pub fn stratified(records: ArrayView<'a, f32, D1>, labels: ArrayView<'a, f32, D2>, k: usize) -> Self {
+        assert_eq!(records.len_of(Axis(0)), labels.len_of(Axis(0)));
+
+        let mut by_class: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+        for (id, row) in labels.outer_iter().enumerate() {
+            let key = class_key(&row);
+            by_class.entry(key).or_default().push(id);
+        }
+
+        let mut folds: Vec<Vec<usize>> = vec![Vec::new(); k];
+        for ids in by_class.into_values() {
+            for (i, id) in ids.into_iter().enumerate() {
+                folds[i % k].push(id);
+            }
+        }
+
+        Self::from_folds(records, labels, folds)
+    }
+
+    /// Builds a K-Fold iterator that guarantees all rows sharing a group id land entirely in
+    /// either the train or the test split of a fold.
+    ///
+    /// Groups are assigned, in first-seen order, whole to the currently smallest fold by row
+    /// count, so fold sizes stay balanced without ever splitting a group across folds.
+    ///
+    /// # Panics
+    ///
+    /// If the number of distinct groups is smaller than `k`.
+    pub fn grouped(records: ArrayView<'a, f32, D1>, labels: ArrayView<'a, f32, D2>, k: usize, group_ids: &[usize]) -> Self {
+        assert_eq!(records.len_of(Axis(0)), labels.len_of(Axis(0)));
+
+        let folds = grouped_folds(records.len_of(Axis(0)), k, group_ids);
+        Self::from_folds(records, labels, folds)
+    }
+}
+
+/// Discretizes a row of labels into a single class key: the argmax for a one-hot row, or the
+/// scalar value itself for a 1-D row.
+fn class_key<D: Dimension>(row: &ArrayView<f32, D>) -> u64 {
+    if row.len() <= 1 {
+        return row.iter().next().copied().unwrap_or(0.).to_bits() as u64;
+    }
+
+    row.iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(i, _)| i as u64)
+        .unwrap_or(0)
+}
+
+/// Greedily assigns whole groups, in first-seen order, to the currently smallest of `k` folds,
+/// returning the resulting per-fold row index lists.
+///
+/// # Panics
+///
+/// If the number of distinct groups is smaller than `k`.
+fn grouped_folds(len: usize, k: usize, group_ids: &[usize]) -> Vec<Vec<usize>> {
+    assert_eq!(group_ids.len(), len);
+
+    let mut by_group: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    let mut order: Vec<usize> = Vec::new();
+    for (id, group) in group_ids.iter().enumerate() {
+        if !by_group.contains_key(group) {
+            order.push(*group);
+        }
+        by_group.entry(*group).or_default().push(id);
+    }
+
+    assert!(order.len() >= k, "error: fewer distinct groups than folds.");
+
+    let mut folds: Vec<Vec<usize>> = vec![Vec::new(); k];
+    for group in order {
+        let mut ids = by_group.remove(&group).unwrap();
+        let smallest = folds
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, fold)| fold.len())
+            .map(|(i, _)| i)
+            .unwrap();
+        folds[smallest].append(&mut ids);
+    }
+
+    folds
 }
 
 impl<'a, D1, D2> Iterator for LabeledKFold<'a, D1, D2>
@@ -818,6 +2232,18 @@ impl<'a, D1: RemoveAxis, D2: RemoveAxis> LabeledBatch<'a, D1, D2> {
 
         self
     }
+
+    /// Shuffles the order in which rows are grouped into batches.
+    ///
+    /// This version allows for a seed to be specified for results reproducibility. Must be called
+    /// before the first call to `next`. The same permutation is used for both `records` and
+    /// `labels`, so rows stay aligned.
+    pub fn shuffle(mut self, seed: u64) -> Self {
+        self.records = self.records.shuffle(seed);
+        self.labels = self.labels.shuffle(seed);
+
+        self
+    }
 }
 
 impl<'a, D1: RemoveAxis, D2: RemoveAxis> Iterator for LabeledBatch<'a, D1, D2> {
@@ -849,6 +2275,35 @@ impl<'a, D: RemoveAxis> KFold<'a, D> {
             k,
         }
     }
+
+    /// Builds a K-Fold iterator that guarantees all rows sharing a group id land entirely in
+    /// either the train or the test split of a fold.
+    ///
+    /// # Panics
+    ///
+    /// If the number of distinct groups is smaller than `k`.
+    pub fn grouped(records: ArrayView<'a, f32, D>, k: usize, group_ids: &[usize]) -> Self {
+        let folds = grouped_folds(records.len_of(Axis(0)), k, group_ids);
+
+        Self {
+            records: SetKFold::from_folds(records, folds),
+            iteration: 0,
+            k,
+        }
+    }
+
+    /// Shuffles the rows before splitting them into contiguous folds, so the dataset's natural
+    /// ordering doesn't leak into every fold.
+    ///
+    /// This version allows for a seed to be specified for results reproducibility. Must be called
+    /// before the first call to `next`.
+    pub fn shuffle(mut self, seed: u64) -> Self {
+        let order = fisher_yates_permutation(self.records.source.len_of(Axis(0)), seed);
+        let folds = chunked_folds(&order, self.k);
+        self.records = SetKFold::from_folds(self.records.source, folds);
+
+        self
+    }
 }
 
 impl <'a, D: RemoveAxis> Iterator for KFold<'a, D> {
@@ -866,5 +2321,338 @@ impl <'a, D: RemoveAxis> Iterator for KFold<'a, D> {
     }
 }
 
+/// Leave-P-Out cross-validator on a dataset.
+///
+/// Yields every split where a distinct size-`p` subset of rows is held out as the test set and
+/// the remaining rows form the train set, i.e. `C(n, p)` folds in total - only practical for
+/// small `n`. Held-out subsets are enumerated in lexicographic order, the way itertools'
+/// `combinations` does.
+pub struct LeavePOut<'a, D> {
+    source: ArrayView<'a, f32, D>,
+    next_test: Option<Vec<usize>>,
+    p: usize,
+}
+
+impl<'a, D: RemoveAxis> LeavePOut<'a, D> {
+    pub fn new(source: ArrayView<'a, f32, D>, p: usize) -> Self {
+        let n = source.len_of(Axis(0));
+        assert!(p >= 1 && p <= n, "error: p must be in [1, n].");
+
+        Self {
+            source,
+            next_test: Some((0..p).collect()),
+            p,
+        }
+    }
+}
+
+impl<'a, D: RemoveAxis> Iterator for LeavePOut<'a, D> {
+    type Item = (Dataset<D>, Dataset<D>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let test_ids = self.next_test.take()?;
+        self.next_test = next_combination(&test_ids, self.source.len_of(Axis(0)));
+
+        let in_test: std::collections::BTreeSet<usize> = test_ids.iter().copied().collect();
+        let train_ids: Vec<usize> = (0..self.source.len_of(Axis(0)))
+            .filter(|id| !in_test.contains(id))
+            .collect();
+
+        Some((
+            Dataset::new(self.source.select(Axis(0), &train_ids)),
+            Dataset::new(self.source.select(Axis(0), &test_ids)),
+        ))
+    }
+}
+
+/// Leave-P-Out cross-validator on a labeled dataset.
+pub struct LabeledLeavePOut<'a, D1, D2> {
+    records: LeavePOut<'a, D1>,
+    labels: LeavePOut<'a, D2>,
+}
+
+impl<'a, D1, D2> LabeledLeavePOut<'a, D1, D2>
+where
+    D1: RemoveAxis,
+    D2: RemoveAxis,
+{
+    pub fn new(records: ArrayView<'a, f32, D1>, labels: ArrayView<'a, f32, D2>, p: usize) -> Self {
+        assert_eq!(records.len_of(Axis(0)), labels.len_of(Axis(0)));
+
+        Self {
+            records: LeavePOut::new(records, p),
+            labels: LeavePOut::new(labels, p),
+        }
+    }
+}
+
+impl<'a, D1, D2> Iterator for LabeledLeavePOut<'a, D1, D2>
+where
+    D1: RemoveAxis,
+    D2: RemoveAxis,
+{
+    type Item = (LabeledDataset<D1, D2>, LabeledDataset<D1, D2>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (train_in, test_in) = self.records.next()?;
+        let (train_out, test_out) = self.labels.next().unwrap();
+
+        Some((
+            LabeledDataset::new(train_in.records, train_out.records),
+            LabeledDataset::new(test_in.records, test_out.records),
+        ))
+    }
+}
+
+/// Advances `current` (a size-`p` subset of `0..n`, sorted ascending) to the next subset in
+/// lexicographic order, the way itertools' `combinations` does: scan from the rightmost position
+/// `j` for the first index that can be incremented (its value `< n - p + j`), increment it, and
+/// reset every index to its right to consecutive values. Returns `None` once no position can
+/// advance.
+fn next_combination(current: &[usize], n: usize) -> Option<Vec<usize>> {
+    let p = current.len();
+    let mut next = current.to_vec();
+
+    for j in (0..p).rev() {
+        if next[j] < n - p + j {
+            next[j] += 1;
+            for k in j + 1..p {
+                next[k] = next[k - 1] + 1;
+            }
+
+            return Some(next);
+        }
+    }
+
+    None
+}
+
+/// Parallel iteration over [`KFold`], [`LabeledKFold`] and [`LabeledBatch`], backed by `rayon`.
+///
+/// Each fold/batch is computed independently from the underlying index and the shared
+/// [`ArrayView`]s, so a [`Producer`] that splits the `0..len` range and calls the existing
+/// `compute_fold`/batch-slicing logic per index fits without re-deriving any splitting logic.
+#[cfg(feature = "rayon")]
+mod rayon_support {
+    use super::*;
+
+    use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+    use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+    macro_rules! indexed_par_iter {
+        ($iter:ident, $producer:ident, <$($generic:ident),+>, $source:ty, $item:ty, $compute:expr) => {
+            pub struct $iter<'a, $($generic),+> {
+                source: $source,
+                start: usize,
+                end: usize,
+            }
+
+            impl<'a, $($generic: RemoveAxis + Sync),+> ParallelIterator for $iter<'a, $($generic),+> {
+                type Item = $item;
+
+                fn drive_unindexed<C>(self, consumer: C) -> C::Result
+                where
+                    C: UnindexedConsumer<Self::Item>,
+                {
+                    bridge(self, consumer)
+                }
+
+                fn opt_len(&self) -> Option<usize> {
+                    Some(self.end - self.start)
+                }
+            }
+
+            impl<'a, $($generic: RemoveAxis + Sync),+> IndexedParallelIterator for $iter<'a, $($generic),+> {
+                fn len(&self) -> usize {
+                    self.end - self.start
+                }
+
+                fn drive<C>(self, consumer: C) -> C::Result
+                where
+                    C: Consumer<Self::Item>,
+                {
+                    bridge(self, consumer)
+                }
+
+                fn with_producer<CB>(self, callback: CB) -> CB::Output
+                where
+                    CB: ProducerCallback<Self::Item>,
+                {
+                    callback.callback($producer {
+                        source: self.source,
+                        start: self.start,
+                        end: self.end,
+                    })
+                }
+            }
+
+            struct $producer<'a, $($generic),+> {
+                source: $source,
+                start: usize,
+                end: usize,
+            }
+
+            impl<'a, $($generic: RemoveAxis + Sync),+> Producer for $producer<'a, $($generic),+> {
+                type Item = $item;
+                type IntoIter = std::vec::IntoIter<Self::Item>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    let compute = $compute;
+                    (self.start..self.end)
+                        .map(|i| compute(&self.source, i))
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                }
+
+                fn split_at(self, index: usize) -> (Self, Self) {
+                    let mid = self.start + index;
+                    (
+                        $producer {
+                            source: self.source.clone(),
+                            start: self.start,
+                            end: mid,
+                        },
+                        $producer {
+                            source: self.source,
+                            start: mid,
+                            end: self.end,
+                        },
+                    )
+                }
+            }
+        };
+    }
+
+    indexed_par_iter!(
+        KFoldParIter,
+        KFoldProducer,
+        <D>,
+        SetKFold<'a, D>,
+        (Dataset<D>, Dataset<D>),
+        |source: &SetKFold<'a, D>, i: usize| {
+            let (train, test) = source.compute_fold(i);
+            (Dataset::new(train), Dataset::new(test))
+        }
+    );
+
+    impl<'a, D: RemoveAxis + Sync> IntoParallelIterator for KFold<'a, D> {
+        type Item = (Dataset<D>, Dataset<D>);
+        type Iter = KFoldParIter<'a, D>;
+
+        fn into_par_iter(self) -> Self::Iter {
+            KFoldParIter {
+                source: self.records,
+                start: 0,
+                end: self.k,
+            }
+        }
+    }
+
+    type LabeledFold<D1, D2> = (LabeledDataset<D1, D2>, LabeledDataset<D1, D2>);
+
+    #[derive(Clone)]
+    struct LabeledKFoldSource<'a, D1, D2> {
+        records: SetKFold<'a, D1>,
+        labels: SetKFold<'a, D2>,
+    }
+
+    indexed_par_iter!(
+        LabeledKFoldParIter,
+        LabeledKFoldProducer,
+        <D1, D2>,
+        LabeledKFoldSource<'a, D1, D2>,
+        LabeledFold<D1, D2>,
+        |source: &LabeledKFoldSource<'a, D1, D2>, i: usize| {
+            let (train_in, test_in) = source.records.compute_fold(i);
+            let (train_out, test_out) = source.labels.compute_fold(i);
+            (
+                LabeledDataset::new(train_in, train_out),
+                LabeledDataset::new(test_in, test_out),
+            )
+        }
+    );
+
+    impl<'a, D1, D2> IntoParallelIterator for LabeledKFold<'a, D1, D2>
+    where
+        D1: RemoveAxis + Sync,
+        D2: RemoveAxis + Sync,
+    {
+        type Item = LabeledFold<D1, D2>;
+        type Iter = LabeledKFoldParIter<'a, D1, D2>;
+
+        fn into_par_iter(self) -> Self::Iter {
+            LabeledKFoldParIter {
+                source: LabeledKFoldSource {
+                    records: self.records,
+                    labels: self.labels,
+                },
+                start: 0,
+                end: self.k,
+            }
+        }
+    }
+
+    /// Addresses [`LabeledBatch`]'s `order` permutation by index, rather than by advancing its
+    /// own `Iterator` impl, so a [`Producer`] can split the batch range directly — this is also
+    /// why a shuffle applied via [`LabeledBatch::shuffle`] composes with parallel iteration rather
+    /// than being ignored: `order` is carried over as-is, the same permutation [`Batch::next`]
+    /// would have walked sequentially.
+    #[derive(Clone)]
+    struct LabeledBatchSource<'a, D1, D2> {
+        records: ArrayView<'a, f32, D1>,
+        records_order: std::sync::Arc<Vec<usize>>,
+        labels: ArrayView<'a, f32, D2>,
+        labels_order: std::sync::Arc<Vec<usize>>,
+        batch_size: usize,
+    }
+
+    type LabeledBatchItem<'a, D1, D2> = (Array<f32, D1>, Array<f32, D2>);
+
+    indexed_par_iter!(
+        LabeledBatchParIter,
+        LabeledBatchProducer,
+        <D1, D2>,
+        LabeledBatchSource<'a, D1, D2>,
+        LabeledBatchItem<'a, D1, D2>,
+        |source: &LabeledBatchSource<'a, D1, D2>, i: usize| {
+            let start = i * source.batch_size;
+            let records_stop = source.records_order.len().min(start + source.batch_size);
+            let labels_stop = source.labels_order.len().min(start + source.batch_size);
+
+            (
+                source.records.select(Axis(0), &source.records_order[start..records_stop]),
+                source.labels.select(Axis(0), &source.labels_order[start..labels_stop]),
+            )
+        }
+    );
+
+    impl<'a, D1, D2> IntoParallelIterator for LabeledBatch<'a, D1, D2>
+    where
+        D1: RemoveAxis + Sync,
+        D2: RemoveAxis + Sync,
+    {
+        type Item = LabeledBatchItem<'a, D1, D2>;
+        type Iter = LabeledBatchParIter<'a, D1, D2>;
+
+        fn into_par_iter(self) -> Self::Iter {
+            let batch_size = self.records.batch_size;
+            let end = (self.records.order.len() + batch_size - 1) / batch_size;
+
+            LabeledBatchParIter {
+                source: LabeledBatchSource {
+                    records: self.records.source,
+                    records_order: std::sync::Arc::new(self.records.order),
+                    labels: self.labels.source,
+                    labels_order: std::sync::Arc::new(self.labels.order),
+                    batch_size,
+                },
+                start: 0,
+                end,
+            }
+        }
+    }
+}
+
+
 #[cfg(test)]
 mod test;
\ No newline at end of file
@@ -0,0 +1,70 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::{cell::RefCell, collections::HashMap, time::Duration};
+
+/// A convolution strategy chosen by [`autotune`] for a given shape signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ConvStrategy {
+    /// Unfold receptive fields into a column matrix and perform one `mm` against the reshaped
+    /// kernel. Fastest for large kernels/channel counts, where the single big GEMM amortizes the
+    /// cost of materializing the column buffer.
+    ImToCol,
+    /// Fall back to the nested-loop kernel in [`conv_math::direct`](crate::conv_math::direct).
+    /// Fastest for small kernels, where im2col's unfold-and-copy pass dominates.
+    Direct,
+}
+
+thread_local! {
+    /// Caches the winning [`ConvStrategy`] per `(in_shape, kernel_shape, stride, dilation,
+    /// padding)` signature, so only the first `forward` call for a given shape pays for
+    /// benchmarking; every later call with the same signature reuses the cached choice.
+    static CACHE: RefCell<HashMap<Vec<usize>, ConvStrategy>> = RefCell::new(HashMap::new());
+}
+
+/// Flattens `(in_shape, kernel_shape, stride, dilation, padding)` into the signature `CACHE` is
+/// keyed on.
+pub(crate) fn signature(
+    in_shape: &[usize],
+    kernel_shape: &[usize],
+    stride: &[usize],
+    dilation: &[usize],
+    padding: &[usize],
+) -> Vec<usize> {
+    in_shape
+        .iter()
+        .chain(kernel_shape)
+        .chain(stride)
+        .chain(dilation)
+        .chain(padding)
+        .copied()
+        .collect()
+}
+
+/// Returns the cached [`ConvStrategy`] for `signature`, benchmarking `im2col` against `direct`
+/// and caching the winner if this is the first time this signature is seen.
+pub(crate) fn autotune(
+    signature: Vec<usize>,
+    im2col: impl FnOnce() -> Duration,
+    direct: impl FnOnce() -> Duration,
+) -> ConvStrategy {
+    if let Some(strategy) = CACHE.with(|cache| cache.borrow().get(&signature).copied()) {
+        return strategy;
+    }
+
+    let strategy = if im2col() <= direct() {
+        ConvStrategy::ImToCol
+    } else {
+        ConvStrategy::Direct
+    };
+    CACHE.with(|cache| cache.borrow_mut().insert(signature, strategy));
+
+    strategy
+}
@@ -0,0 +1,304 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{Array, Array2, ArrayD, ArrayViewD, Axis, Dimension, IxDyn, RemoveAxis, Slice, Zip};
+
+use tactics_variable::{PaddingMode, VarDiff};
+
+/// Pads every sample of the batch `input` with `padding_mode`, where `padding` holds one entry per
+/// sample axis (i.e. every axis of `input` except the leading batch one), in the same order
+/// [`PaddingMode::pad`] expects.
+pub(crate) fn pad_batch<D, T>(input: &Array<f32, D>, padding_mode: &T, padding: &[usize]) -> Array<f32, D>
+    where
+        D: Dimension + RemoveAxis,
+        D::Smaller: RemoveAxis,
+        T: PaddingMode<D>,
+{
+    let mut padded_shape = input.raw_dim();
+    for (axis, &pad) in padding.iter().enumerate() {
+        padded_shape[axis + 1] += 2 * pad;
+    }
+
+    let mut padded = Array::zeros(padded_shape);
+    let mut pad_dim = D::Smaller::zeros(padding.len());
+    for (axis, &pad) in padding.iter().enumerate() {
+        pad_dim[axis] = pad;
+    }
+
+    for (base, mut dest) in input.outer_iter().zip(padded.outer_iter_mut()) {
+        padding_mode.pad(&mut dest, &base, pad_dim.clone());
+    }
+
+    padded
+}
+
+/// Walks every multi-index of a (row-major) shape with `dims` elements per axis, in ascending
+/// order.
+fn indices(dims: &[usize]) -> impl Iterator<Item = Vec<usize>> + '_ {
+    let total: usize = dims.iter().product();
+    (0..total).map(move |flat| {
+        let mut flat = flat;
+        let mut index = vec![0; dims.len()];
+        for axis in (0..dims.len()).rev() {
+            index[axis] = flat % dims[axis];
+            flat /= dims[axis];
+        }
+        index
+    })
+}
+
+/// Spatial extent of a convolution's output given the (already padded) spatial extent of its
+/// input.
+fn out_spatial(in_spatial: &[usize], kernel: &[usize], stride: &[usize], dilation: &[usize]) -> Vec<usize> {
+    (0..kernel.len())
+        .map(|axis| (in_spatial[axis] - dilation[axis] * (kernel[axis] - 1) - 1) / stride[axis] + 1)
+        .collect()
+}
+
+/// Direct, nested-loop cross-correlation: accumulates one output element at a time straight from
+/// `input` and `weight`, without materializing an intermediate column matrix. Cheapest for small
+/// kernels, where im2col's unfold-and-copy pass would dominate — the strategy [`ConvStrategy::Direct`](crate::conv_autotune::ConvStrategy::Direct) picks.
+///
+/// `input` is `(N, Cin, *spatial)`, already padded; `weight` is `(Cout, Cin / groups, *kernel)`.
+/// Returns `(N, Cout, *out_spatial)`, without the bias added.
+pub(crate) fn direct(
+    input: &ArrayViewD<f32>,
+    weight: &ArrayViewD<f32>,
+    stride: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) -> ArrayD<f32> {
+    let batch = input.shape()[0];
+    let out_channels = weight.shape()[0];
+    let in_channels_per_group = weight.shape()[1];
+    let out_channels_per_group = out_channels / groups;
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let out_spatial = out_spatial(&input.shape()[2..], &kernel_shape, stride, dilation);
+
+    let mut out_shape = vec![batch, out_channels];
+    out_shape.extend(&out_spatial);
+    let mut output = ArrayD::zeros(IxDyn(&out_shape));
+
+    for n in 0..batch {
+        for group in 0..groups {
+            for oc in 0..out_channels_per_group {
+                let out_channel = group * out_channels_per_group + oc;
+                for out_pos in indices(&out_spatial) {
+                    let mut acc = 0.;
+                    for ic in 0..in_channels_per_group {
+                        let in_channel = group * in_channels_per_group + ic;
+                        for k_pos in indices(&kernel_shape) {
+                            let in_pos: Vec<usize> = out_pos
+                                .iter()
+                                .zip(&k_pos)
+                                .enumerate()
+                                .map(|(axis, (&o, &k))| o * stride[axis] + k * dilation[axis])
+                                .collect();
+
+                            let mut in_index = vec![n, in_channel];
+                            in_index.extend(in_pos);
+                            let mut w_index = vec![out_channel, ic];
+                            w_index.extend(k_pos);
+
+                            acc += input[IxDyn(&in_index)] * weight[IxDyn(&w_index)];
+                        }
+                    }
+
+                    let mut out_index = vec![n, out_channel];
+                    out_index.extend(out_pos);
+                    output[IxDyn(&out_index)] = acc;
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// im2col/GEMM cross-correlation: unfolds every receptive field touched by one group into a column
+/// of a `(Cin_per_group * kernel_elems, N * out_positions)` matrix, then computes that group's
+/// contribution as a single matrix multiplication against the kernel reshaped to a
+/// `(Cout_per_group, Cin_per_group * kernel_elems)` matrix. Cheapest for large kernels/channel
+/// counts, where the one big GEMM amortizes the cost of materializing the column matrix — the
+/// strategy [`ConvStrategy::ImToCol`](crate::conv_autotune::ConvStrategy::ImToCol) picks.
+///
+/// Same shapes and contract as [`direct`].
+pub(crate) fn im2col(
+    input: &ArrayViewD<f32>,
+    weight: &ArrayViewD<f32>,
+    stride: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) -> ArrayD<f32> {
+    let batch = input.shape()[0];
+    let out_channels = weight.shape()[0];
+    let in_channels_per_group = weight.shape()[1];
+    let out_channels_per_group = out_channels / groups;
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let kernel_elems: usize = kernel_shape.iter().product();
+    let out_spatial = out_spatial(&input.shape()[2..], &kernel_shape, stride, dilation);
+    let out_positions: usize = out_spatial.iter().product();
+
+    let positions: Vec<(usize, Vec<usize>)> = (0..batch)
+        .flat_map(|n| indices(&out_spatial).map(move |out_pos| (n, out_pos)))
+        .collect();
+    let receptive_field: Vec<(usize, Vec<usize>)> = (0..in_channels_per_group)
+        .flat_map(|ic| indices(&kernel_shape).map(move |k_pos| (ic, k_pos)))
+        .collect();
+
+    let mut out_shape = vec![batch, out_channels];
+    out_shape.extend(&out_spatial);
+    let mut output = ArrayD::zeros(IxDyn(&out_shape));
+
+    for group in 0..groups {
+        let mut columns = Array2::<f32>::zeros((receptive_field.len(), positions.len()));
+        for (column, (n, out_pos)) in positions.iter().enumerate() {
+            for (row, (ic, k_pos)) in receptive_field.iter().enumerate() {
+                let in_channel = group * in_channels_per_group + ic;
+                let in_pos: Vec<usize> = out_pos
+                    .iter()
+                    .zip(k_pos)
+                    .enumerate()
+                    .map(|(axis, (&o, &k))| o * stride[axis] + k * dilation[axis])
+                    .collect();
+
+                let mut in_index = vec![*n, in_channel];
+                in_index.extend(in_pos);
+                columns[[row, column]] = input[IxDyn(&in_index)];
+            }
+        }
+
+        let group_start = (group * out_channels_per_group) as isize;
+        let kernel_matrix = weight
+            .slice_axis(Axis(0), Slice::from(group_start..group_start + out_channels_per_group as isize))
+            .to_owned()
+            .into_shape((out_channels_per_group, receptive_field.len()))
+            .expect("error: kernel slice could not be reshaped into a (Cout/groups, Cin/groups * kernel_elems) matrix");
+
+        let group_output = kernel_matrix.dot(&columns);
+
+        for (column, (n, out_pos)) in positions.iter().enumerate() {
+            for oc in 0..out_channels_per_group {
+                let out_channel = group * out_channels_per_group + oc;
+                let mut out_index = vec![*n, out_channel];
+                out_index.extend(out_pos.clone());
+                output[IxDyn(&out_index)] = group_output[[oc, column]];
+            }
+        }
+    }
+
+    output
+}
+
+/// Transposed convolution: scatters every input element, scaled by the kernel, into a larger
+/// output grid, with `stride` acting as an upsampling factor — the forward values of the
+/// input-gradient of a regular convolution.
+///
+/// `input` is `(N, Cin, *spatial)`; `weight` is `(Cin, Cout, *kernel)`, note the channel transpose
+/// relative to [`direct`]/[`im2col`]. `padding` and `output_padding` hold one entry per spatial
+/// axis: a *full* output of extent `(spatial - 1) * stride + dilation * (kernel - 1) + 1` is
+/// scattered into first, then cropped by `padding` on both sides and extended by `output_padding`
+/// on the high side, mirroring how a regular convolution's padding and an odd input extent are
+/// recovered on the way back. Returns `(N, Cout, *out_spatial)`, without the bias added.
+pub(crate) fn transpose(
+    input: &ArrayViewD<f32>,
+    weight: &ArrayViewD<f32>,
+    stride: &[usize],
+    dilation: &[usize],
+    padding: &[usize],
+    output_padding: &[usize],
+) -> ArrayD<f32> {
+    let batch = input.shape()[0];
+    let in_channels = input.shape()[1];
+    let out_channels = weight.shape()[1];
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let in_spatial = input.shape()[2..].to_vec();
+
+    let full_spatial: Vec<usize> = (0..kernel_shape.len())
+        .map(|axis| (in_spatial[axis] - 1) * stride[axis] + dilation[axis] * (kernel_shape[axis] - 1) + 1)
+        .collect();
+    let out_spatial: Vec<usize> = (0..kernel_shape.len())
+        .map(|axis| full_spatial[axis] + output_padding[axis] - 2 * padding[axis])
+        .collect();
+
+    let mut out_shape = vec![batch, out_channels];
+    out_shape.extend(&out_spatial);
+    let mut output = ArrayD::zeros(IxDyn(&out_shape));
+
+    for n in 0..batch {
+        for cin in 0..in_channels {
+            for in_pos in indices(&in_spatial) {
+                let mut in_index = vec![n, cin];
+                in_index.extend(in_pos.clone());
+                let in_val = input[IxDyn(&in_index)];
+
+                for cout in 0..out_channels {
+                    for k_pos in indices(&kernel_shape) {
+                        let full_pos: Vec<usize> = in_pos
+                            .iter()
+                            .zip(&k_pos)
+                            .enumerate()
+                            .map(|(axis, (&i, &k))| i * stride[axis] + k * dilation[axis])
+                            .collect();
+
+                        if full_pos
+                            .iter()
+                            .enumerate()
+                            .any(|(axis, &p)| p < padding[axis] || p - padding[axis] >= out_spatial[axis])
+                        {
+                            continue;
+                        }
+                        let out_pos: Vec<usize> = full_pos
+                            .iter()
+                            .enumerate()
+                            .map(|(axis, &p)| p - padding[axis])
+                            .collect();
+
+                        let mut w_index = vec![cin, cout];
+                        w_index.extend(k_pos);
+                        let mut out_index = vec![n, cout];
+                        out_index.extend(out_pos);
+
+                        output[IxDyn(&out_index)] += in_val * weight[IxDyn(&w_index)];
+                    }
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Broadcast-adds a `(Cout, 1, ..., 1)` bias over a `(N, Cout, *spatial)` convolution output.
+pub(crate) fn add_bias(output: &mut ArrayD<f32>, bias: &ArrayD<f32>) {
+    let bias = bias.view().insert_axis(Axis(0));
+    let bias = bias.broadcast(output.raw_dim()).expect("error: bias is not broadcastable over the convolution output");
+    Zip::from(&mut *output).and(&bias).for_each(|out, &b| *out += b);
+}
+
+/// Builds a fresh differentiable leaf of `data`'s shape, filled with `data`.
+///
+/// Used by every convolution's `forward` below. `tactics_variable` now carries real `Forward`/
+/// `Backward` node pairs for both plain/grouped convolution and transposed convolution
+/// (`tactics_variable::node::{Convolution, ConvTranspose}`, alongside their `*Backward`
+/// counterparts) that correctly accumulate gradients back into `weight` and `input` via col2im-
+/// style scatter-accumulation, mirroring [`crate`]'s own `direct`/`im2col`/`transpose` math. What's
+/// still missing, here as for every other node in that crate (`gather`, `softmax`, ... — none of
+/// them have a call site anywhere in this crate either), is the graph-wiring layer: a public
+/// `VarDiff` method that builds one of these nodes, threads it into the operands' `History`, and
+/// returns the `VarDiff` wrapping its output — that plumbing does not exist in this snapshot for
+/// any op, convolution included. Until it does, a convolution's *output* becomes a new,
+/// independent leaf rather than one through which gradients flow back to `weight`, `bias` or
+/// `input` — only the forward values are correct.
+pub(crate) fn leaf<D: Dimension>(data: Array<f32, D>) -> VarDiff<D> {
+    let mut var = tactics_variable::zeros(data.raw_dim()).requires_grad();
+    var.data_mut().assign(&data);
+    var
+}
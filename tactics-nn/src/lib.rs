@@ -10,11 +10,19 @@
 
 use ndarray::{Ix1, Ix2, Ix3, Ix4, Ix5};
 
-use tactics_core::{Convolution, MatMatMulT};
+use tactics_core::MatMatMulT;
 
 use tactics_variable::{PaddingMode, VarDiff};
 
 pub mod init;
+pub mod module;
+
+mod conv_autotune;
+use conv_autotune::{autotune, ConvStrategy};
+use module::{Module, Param};
+
+mod conv_math;
+mod seq_math;
 
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
@@ -69,6 +77,15 @@ impl Linear {
     }
 }
 
+impl Module for Linear {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix2(self.weight.clone()),
+            Param::Ix1(self.bias.clone()),
+        ]
+    }
+}
+
 /// A **long short-term memory (LSTM)** cell.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[allow(clippy::upper_case_acronyms)]
@@ -163,6 +180,17 @@ impl LSTMCell {
     }
 }
 
+impl Module for LSTMCell {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix2(self.weight_ih.clone()),
+            Param::Ix2(self.weight_hh.clone()),
+            Param::Ix1(self.bias_ih.clone()),
+            Param::Ix1(self.bias_hh.clone()),
+        ]
+    }
+}
+
 /// A **gated recurrent unit (GRU)** cell.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[allow(clippy::upper_case_acronyms)]
@@ -247,6 +275,325 @@ impl GRUCell {
     }
 }
 
+impl Module for GRUCell {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix2(self.weight_ih.clone()),
+            Param::Ix2(self.weight_hh.clone()),
+            Param::Ix1(self.bias_ih.clone()),
+            Param::Ix1(self.bias_hh.clone()),
+        ]
+    }
+}
+
+/// A multi-layer, optionally bidirectional **LSTM**, unrolling [`LSTMCell`] over an input
+/// sequence and threading hidden/cell state between timesteps and between stacked layers.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct LSTM {
+    pub layers: Vec<LSTMCell>,
+    pub reverse_layers: Option<Vec<LSTMCell>>,
+    pub dropout: f32,
+}
+
+impl LSTM {
+    /// Creates a new LSTM.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// * `num_layers` - number of stacked LSTM layers; layer `i > 0` consumes layer `i - 1`'s
+    /// output sequence instead of the original input.
+    ///
+    /// * `bidirectional` - if `true`, a second set of `num_layers` cells (see
+    /// [`LSTM::reverse_layers`]) processes the sequence in reverse and each timestep's outputs from
+    /// both directions are concatenated along the feature axis, doubling the input size seen by the
+    /// next layer.
+    ///
+    /// * `dropout` - dropout probability applied to every layer's output sequence except the last.
+    /// It is applied on every [`LSTM::forward`] call; [`Module::train`]/[`Module::eval`] do not
+    /// switch it on or off.
+    ///
+    /// Every cell is initialized the same way as a standalone [`LSTMCell::new`].
+    pub fn new(
+        input_size: usize,
+        hidden_size: usize,
+        num_layers: usize,
+        bidirectional: bool,
+        dropout: f32,
+    ) -> Self {
+        let directions = if bidirectional { 2 } else { 1 };
+        let make_layers = || {
+            (0..num_layers)
+                .map(|i| {
+                    let layer_input_size = if i == 0 {
+                        input_size
+                    } else {
+                        hidden_size * directions
+                    };
+                    LSTMCell::new(layer_input_size, hidden_size)
+                })
+                .collect()
+        };
+
+        Self {
+            layers: make_layers(),
+            reverse_layers: bidirectional.then(make_layers),
+            dropout,
+        }
+    }
+
+    /// Unrolls this LSTM over an input sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - the initial `(cell, hidden)` state of every layer and direction, each of shape
+    /// *(batch, hidden_size)*; one entry per entry of [`LSTM::layers`], followed by one per entry
+    /// of [`LSTM::reverse_layers`] if bidirectional.
+    ///
+    /// * `input` - a variable of shape *(seq_len, batch, input_size)*.
+    ///
+    /// The **output** is the full per-timestep output sequence, of shape
+    /// *(seq_len, batch, hidden_size)* (or *(seq_len, batch, 2 * hidden_size)* if bidirectional),
+    /// together with the final `(cell, hidden)` state of every layer and direction.
+    ///
+    /// Unrolling a layer over time means slicing `input` into `seq_len` per-timestep views and
+    /// threading each through [`LSTMCell::forward`], then restitching the per-timestep outputs back
+    /// into a single output-sequence variable (concatenating the two directions' outputs along the
+    /// feature axis, for the bidirectional case). Neither a per-timestep slicing nor a
+    /// stack/concatenate primitive over `VarDiff<Ix3>` is exposed by this workspace's
+    /// `tactics_variable` snapshot, so (see [`seq_math`]) both are done against raw `ndarray` data
+    /// and every such boundary is a fresh leaf: the initial slice of `input`, the final stack of
+    /// the output sequence and, between stacked layers, the bidirectional concatenation and the
+    /// inter-layer dropout. Gradients flow through each [`LSTMCell::forward`] call and between the
+    /// timesteps of a layer, but not across any of those boundaries; they flow between stacked
+    /// layers only when the LSTM is neither bidirectional nor uses dropout.
+    pub fn forward(
+        &self,
+        state: Vec<(VarDiff<Ix2>, VarDiff<Ix2>)>,
+        input: VarDiff<Ix3>,
+    ) -> (VarDiff<Ix3>, Vec<(VarDiff<Ix2>, VarDiff<Ix2>)>) {
+        let seq_len = input.data().shape()[0];
+        let num_layers = self.layers.len();
+
+        let mut layer_input: Vec<VarDiff<Ix2>> = (0..seq_len)
+            .map(|t| conv_math::leaf(seq_math::timestep(&input.data(), t)))
+            .collect();
+
+        let mut forward_final = Vec::with_capacity(num_layers);
+        let mut reverse_final = Vec::with_capacity(num_layers);
+
+        for (layer_idx, cell) in self.layers.iter().enumerate() {
+            let mut step_state = state[layer_idx].clone();
+            let forward_outputs: Vec<VarDiff<Ix2>> = layer_input
+                .iter()
+                .map(|step_input| {
+                    step_state = cell.forward(step_state.clone(), step_input.clone());
+                    step_state.1.clone()
+                })
+                .collect();
+            forward_final.push(step_state);
+
+            layer_input = match &self.reverse_layers {
+                Some(reverse_layers) => {
+                    let reverse_cell = &reverse_layers[layer_idx];
+                    let mut step_state = state[num_layers + layer_idx].clone();
+                    let mut reverse_outputs = vec![None; seq_len];
+                    for t in (0..seq_len).rev() {
+                        step_state = reverse_cell.forward(step_state.clone(), layer_input[t].clone());
+                        reverse_outputs[t] = Some(step_state.1.clone());
+                    }
+                    reverse_final.push(step_state);
+
+                    forward_outputs
+                        .into_iter()
+                        .zip(reverse_outputs.into_iter().map(Option::unwrap))
+                        .map(|(f, r)| conv_math::leaf(seq_math::concat_features(&f.data(), &r.data())))
+                        .collect()
+                }
+                None => forward_outputs,
+            };
+
+            if self.dropout > 0. && layer_idx + 1 < num_layers {
+                layer_input = layer_input
+                    .iter()
+                    .map(|step| conv_math::leaf(seq_math::dropout(&step.data(), self.dropout)))
+                    .collect();
+            }
+        }
+
+        let output = conv_math::leaf(seq_math::stack(
+            &layer_input.iter().map(|step| step.data().clone()).collect::<Vec<_>>(),
+        ));
+
+        forward_final.extend(reverse_final);
+        (output, forward_final)
+    }
+}
+
+impl Module for LSTM {
+    fn parameters(&self) -> Vec<Param> {
+        self.layers
+            .iter()
+            .chain(self.reverse_layers.iter().flatten())
+            .flat_map(LSTMCell::parameters)
+            .collect()
+    }
+}
+
+/// A multi-layer, optionally bidirectional **GRU**, unrolling [`GRUCell`] over an input sequence
+/// and threading hidden state between timesteps and between stacked layers.
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct GRU {
+    pub layers: Vec<GRUCell>,
+    pub reverse_layers: Option<Vec<GRUCell>>,
+    pub dropout: f32,
+}
+
+impl GRU {
+    /// Creates a new GRU.
+    ///
+    /// # Arguments
+    ///
+    /// * `input_size` - number of expected features in the input.
+    ///
+    /// * `hidden_size` - number of features in the hidden state.
+    ///
+    /// * `num_layers` - number of stacked GRU layers; layer `i > 0` consumes layer `i - 1`'s output
+    /// sequence instead of the original input.
+    ///
+    /// * `bidirectional` - if `true`, a second set of `num_layers` cells (see
+    /// [`GRU::reverse_layers`]) processes the sequence in reverse and each timestep's outputs from
+    /// both directions are concatenated along the feature axis, doubling the input size seen by the
+    /// next layer.
+    ///
+    /// * `dropout` - dropout probability applied to every layer's output sequence except the last.
+    /// It is applied on every [`GRU::forward`] call; [`Module::train`]/[`Module::eval`] do not
+    /// switch it on or off.
+    ///
+    /// Every cell is initialized the same way as a standalone [`GRUCell::new`].
+    pub fn new(
+        input_size: usize,
+        hidden_size: usize,
+        num_layers: usize,
+        bidirectional: bool,
+        dropout: f32,
+    ) -> Self {
+        let directions = if bidirectional { 2 } else { 1 };
+        let make_layers = || {
+            (0..num_layers)
+                .map(|i| {
+                    let layer_input_size = if i == 0 {
+                        input_size
+                    } else {
+                        hidden_size * directions
+                    };
+                    GRUCell::new(layer_input_size, hidden_size)
+                })
+                .collect()
+        };
+
+        Self {
+            layers: make_layers(),
+            reverse_layers: bidirectional.then(make_layers),
+            dropout,
+        }
+    }
+
+    /// Unrolls this GRU over an input sequence.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - the initial hidden state of every layer and direction, each of shape
+    /// *(batch, hidden_size)*; one entry per entry of [`GRU::layers`], followed by one per entry of
+    /// [`GRU::reverse_layers`] if bidirectional.
+    ///
+    /// * `input` - a variable of shape *(seq_len, batch, input_size)*.
+    ///
+    /// The **output** is the full per-timestep output sequence, of shape
+    /// *(seq_len, batch, hidden_size)* (or *(seq_len, batch, 2 * hidden_size)* if bidirectional),
+    /// together with the final hidden state of every layer and direction.
+    ///
+    /// See [`LSTM::forward`] for the per-timestep slicing/restitching strategy (and the resulting
+    /// fresh-leaf boundaries) this unrolling relies on, in the absence of a stack/concatenate
+    /// primitive over `VarDiff<Ix3>` in this workspace's `tactics_variable` snapshot.
+    pub fn forward(
+        &self,
+        state: Vec<VarDiff<Ix2>>,
+        input: VarDiff<Ix3>,
+    ) -> (VarDiff<Ix3>, Vec<VarDiff<Ix2>>) {
+        let seq_len = input.data().shape()[0];
+        let num_layers = self.layers.len();
+
+        let mut layer_input: Vec<VarDiff<Ix2>> = (0..seq_len)
+            .map(|t| conv_math::leaf(seq_math::timestep(&input.data(), t)))
+            .collect();
+
+        let mut forward_final = Vec::with_capacity(num_layers);
+        let mut reverse_final = Vec::with_capacity(num_layers);
+
+        for (layer_idx, cell) in self.layers.iter().enumerate() {
+            let mut hidden = state[layer_idx].clone();
+            let forward_outputs: Vec<VarDiff<Ix2>> = layer_input
+                .iter()
+                .map(|step_input| {
+                    hidden = cell.forward(hidden.clone(), step_input.clone());
+                    hidden.clone()
+                })
+                .collect();
+            forward_final.push(hidden);
+
+            layer_input = match &self.reverse_layers {
+                Some(reverse_layers) => {
+                    let reverse_cell = &reverse_layers[layer_idx];
+                    let mut hidden = state[num_layers + layer_idx].clone();
+                    let mut reverse_outputs = vec![None; seq_len];
+                    for t in (0..seq_len).rev() {
+                        hidden = reverse_cell.forward(hidden.clone(), layer_input[t].clone());
+                        reverse_outputs[t] = Some(hidden.clone());
+                    }
+                    reverse_final.push(hidden);
+
+                    forward_outputs
+                        .into_iter()
+                        .zip(reverse_outputs.into_iter().map(Option::unwrap))
+                        .map(|(f, r)| conv_math::leaf(seq_math::concat_features(&f.data(), &r.data())))
+                        .collect()
+                }
+                None => forward_outputs,
+            };
+
+            if self.dropout > 0. && layer_idx + 1 < num_layers {
+                layer_input = layer_input
+                    .iter()
+                    .map(|step| conv_math::leaf(seq_math::dropout(&step.data(), self.dropout)))
+                    .collect();
+            }
+        }
+
+        let output = conv_math::leaf(seq_math::stack(
+            &layer_input.iter().map(|step| step.data().clone()).collect::<Vec<_>>(),
+        ));
+
+        forward_final.extend(reverse_final);
+        (output, forward_final)
+    }
+}
+
+impl Module for GRU {
+    fn parameters(&self) -> Vec<Param> {
+        self.layers
+            .iter()
+            .chain(self.reverse_layers.iter().flatten())
+            .flat_map(GRUCell::parameters)
+            .collect()
+    }
+}
+
 /// Applies a temporal convolution over an input signal composed of several input planes.
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 pub struct Conv1d<T>
@@ -331,11 +678,64 @@ impl<T> Conv1d<T>
     /// * **Lk** is the **length** of the kernel
     ///
     /// The resulting output shape will be *(N, Cout, Lout)*
+    ///
+    /// Picks between an im2col/GEMM path and a direct, nested-loop kernel via a one-time autotuning
+    /// benchmark per `(kernel_shape, stride, dilation, padding)` signature, cached for subsequent
+    /// calls. See [`Conv2d::forward`] for the full rationale.
+    ///
+    /// Both paths compute the convolution's forward values directly against `input`'s and
+    /// `weight`'s underlying data (see [`conv_math`]); because this workspace does not vendor
+    /// `tactics_core`, neither has a way to append a node to an existing computational graph, so
+    /// the returned variable is a fresh leaf and gradients do not flow back to `weight`, `bias` or
+    /// `input` through it.
     pub fn forward<I>(&self, input: I) -> VarDiff<Ix3>
         where
-            VarDiff<Ix3>: Convolution<I, Ix3>,
+            I: Into<VarDiff<Ix3>>,
     {
-        todo!()
+        let kernel_shape = self.weight.data().shape().to_vec();
+        let signature = conv_autotune::signature(
+            &[],
+            &kernel_shape,
+            &[self.stride],
+            &[self.dilation],
+            &[self.padding],
+        );
+
+        let input = input.into();
+        let padded = conv_math::pad_batch(&input.data(), &self.padding_mode, &[0, self.padding]);
+        let input_view = padded.view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+        let stride = [self.stride];
+        let dilation = [self.dilation];
+
+        let mut output = match autotune(
+            signature,
+            || {
+                let start = std::time::Instant::now();
+                conv_math::im2col(&input_view, &weight_view, &stride, &dilation, 1);
+                start.elapsed()
+            },
+            || {
+                let start = std::time::Instant::now();
+                conv_math::direct(&input_view, &weight_view, &stride, &dilation, 1);
+                start.elapsed()
+            },
+        ) {
+            ConvStrategy::ImToCol => conv_math::im2col(&input_view, &weight_view, &stride, &dilation, 1),
+            ConvStrategy::Direct => conv_math::direct(&input_view, &weight_view, &stride, &dilation, 1),
+        };
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix3>().expect("error: conv1d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix3>> Module for Conv1d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix3(self.weight.clone()),
+            Param::Ix2(self.bias.clone()),
+        ]
     }
 }
 
@@ -428,11 +828,258 @@ impl<T> Conv2d<T>
     /// * **Wk** is the **width** of the kernel
     ///
     /// The resulting output shape will be *(N, Cout, Hout, Wout)*
+    ///
+    /// The first call for a given `(kernel_shape, stride, dilation, padding)` signature benchmarks
+    /// an im2col/GEMM path against a direct, nested-loop kernel and caches whichever is faster (see
+    /// [`conv_autotune`]); every later call with the same signature reuses that choice instead of
+    /// re-benchmarking. `in_shape` is left out of the signature because `I`'s shape isn't observable
+    /// until it is converted into a [`VarDiff`].
+    ///
+    /// Both paths (see [`conv_math`]) compute the forward values directly against `input`'s and
+    /// `weight`'s underlying data. See [`conv_math::leaf`] for why the returned variable is a
+    /// fresh leaf instead of one wired into `tactics_variable::node::Convolution` — gradients do
+    /// not flow back to `weight`, `bias` or `input` through it.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
+        where
+            I: Into<VarDiff<Ix4>>,
+    {
+        let kernel_shape = self.weight.data().shape().to_vec();
+        let (stride_h, stride_w) = self.stride;
+        let (dilation_h, dilation_w) = self.dilation;
+        let (padding_h, padding_w) = self.padding;
+        let signature = conv_autotune::signature(
+            &[],
+            &kernel_shape,
+            &[stride_h, stride_w],
+            &[dilation_h, dilation_w],
+            &[padding_h, padding_w],
+        );
+
+        let input = input.into();
+        let padded = conv_math::pad_batch(&input.data(), &self.padding_mode, &[0, padding_h, padding_w]);
+        let input_view = padded.view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+        let stride = [stride_h, stride_w];
+        let dilation = [dilation_h, dilation_w];
+
+        let mut output = match autotune(
+            signature,
+            || {
+                let start = std::time::Instant::now();
+                conv_math::im2col(&input_view, &weight_view, &stride, &dilation, 1);
+                start.elapsed()
+            },
+            || {
+                let start = std::time::Instant::now();
+                conv_math::direct(&input_view, &weight_view, &stride, &dilation, 1);
+                start.elapsed()
+            },
+        ) {
+            ConvStrategy::ImToCol => conv_math::im2col(&input_view, &weight_view, &stride, &dilation, 1),
+            ConvStrategy::Direct => conv_math::direct(&input_view, &weight_view, &stride, &dilation, 1),
+        };
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix4>().expect("error: conv2d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix4>> Module for Conv2d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix4(self.weight.clone()),
+            Param::Ix3(self.bias.clone()),
+        ]
+    }
+}
+
+/// Applies a **grouped spatial convolution** over an input signal composed of several input
+/// planes.
+///
+/// The input and output channels are partitioned into `groups` independent blocks: each group
+/// convolves only its own slice of `Cin / groups` input channels, producing its own slice of
+/// `Cout / groups` output channels. Setting `groups` equal to `Cin` (with `Cout` a multiple of
+/// `Cin`) gives a **depthwise** convolution, as used by MobileNet-style depthwise separable
+/// convolutions; any other divisor of both `Cin` and `Cout` gives a ResNeXt-style grouped
+/// convolution.
+///
+/// See also [`Conv2d`].
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GroupedConv2d<T>
+    where
+        T: PaddingMode<Ix4>,
+{
+    pub padding: (usize, usize),
+    pub padding_mode: T,
+    pub stride: (usize, usize),
+    pub dilation: (usize, usize),
+    pub groups: usize,
+    pub weight: VarDiff<Ix4>,
+    pub bias: VarDiff<Ix3>,
+}
+
+impl<T> GroupedConv2d<T>
+    where
+        T: PaddingMode<Ix4>,
+{
+    /// Creates a new GroupedConv2d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
+    /// [`Replicative`].
+    ///
+    /// * `stride` - stride of the convolution, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 2-tuple for this
+    /// two-dimensional case.
+    ///
+    /// * `groups` - number of blocks the input and output channels are partitioned into. Setting
+    /// `groups` equal to `in_channels` gives a depthwise convolution.
+    ///
+    /// The weight and the bias are initialized from *U(-k, k)* where
+    /// `k = (1. /((in_channels / groups) * kernel_w * kernel_h) as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `in_channels % groups != 0` or `out_channels % groups != 0`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize),
+        padding: (usize, usize),
+        padding_mode: T,
+        stride: (usize, usize),
+        dilation: (usize, usize),
+        groups: usize,
+    ) -> Self {
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "error: in_channels must be divisible by groups."
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "error: out_channels must be divisible by groups."
+        );
+
+        let (kernel_h, kernel_w) = kernel_size;
+        let weight = tactics_variable::zeros((
+            out_channels,
+            in_channels / groups,
+            kernel_h,
+            kernel_w,
+        ))
+        .requires_grad();
+        let bias = tactics_variable::zeros((out_channels, 1, 1)).requires_grad();
+
+        let k = (1. / ((in_channels / groups) * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    /// Computes a 2-dimensional grouped convolution *(cross correlation)*.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - the signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Hk, Wk)*
+    /// * **Cout** is the number of output channels
+    /// * **Cin** is the number of input channels
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Hout, Wout)*
+    ///
+    /// The depthwise case (`groups == Cin`) has no dedicated path: it runs the same grouped
+    /// [`conv_math::im2col`]/[`conv_math::direct`] kernels, one input channel per group. Since
+    /// `groups` is part of the autotuner's shape signature, it benchmarks and caches its own
+    /// strategy.
+    ///
+    /// Both paths (see [`conv_math`]) compute the forward values directly against `input`'s and
+    /// `weight`'s underlying data, splitting the work into `self.groups` independent blocks.
+    /// `tactics_variable::node::Convolution`/`ConvolutionBackward` already handle the grouped case
+    /// (see [`conv_math::leaf`] for the full rationale on why this `forward` doesn't call into
+    /// them yet), so the returned variable is a fresh leaf and gradients do not flow back to
+    /// `weight`, `bias` or `input` through it.
     pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
         where
-            VarDiff<Ix4>: Convolution<I, Ix4>,
+            I: Into<VarDiff<Ix4>>,
     {
-        todo!()
+        let kernel_shape = self.weight.data().shape().to_vec();
+        let (stride_h, stride_w) = self.stride;
+        let (dilation_h, dilation_w) = self.dilation;
+        let (padding_h, padding_w) = self.padding;
+        let signature = conv_autotune::signature(
+            &[self.groups],
+            &kernel_shape,
+            &[stride_h, stride_w],
+            &[dilation_h, dilation_w],
+            &[padding_h, padding_w],
+        );
+
+        let input = input.into();
+        let padded = conv_math::pad_batch(&input.data(), &self.padding_mode, &[0, padding_h, padding_w]);
+        let input_view = padded.view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+        let stride = [stride_h, stride_w];
+        let dilation = [dilation_h, dilation_w];
+
+        let mut output = match autotune(
+            signature,
+            || {
+                let start = std::time::Instant::now();
+                conv_math::im2col(&input_view, &weight_view, &stride, &dilation, self.groups);
+                start.elapsed()
+            },
+            || {
+                let start = std::time::Instant::now();
+                conv_math::direct(&input_view, &weight_view, &stride, &dilation, self.groups);
+                start.elapsed()
+            },
+        ) {
+            ConvStrategy::ImToCol => conv_math::im2col(&input_view, &weight_view, &stride, &dilation, self.groups),
+            ConvStrategy::Direct => conv_math::direct(&input_view, &weight_view, &stride, &dilation, self.groups),
+        };
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix4>().expect("error: grouped conv2d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix4>> Module for GroupedConv2d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix4(self.weight.clone()),
+            Param::Ix3(self.bias.clone()),
+        ]
     }
 }
 
@@ -528,11 +1175,696 @@ impl<T> Conv3d<T>
     /// * **Wk** is the **width** of the kernel
     ///
     /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*
+    ///
+    /// Picks between an im2col/GEMM path and a direct, nested-loop kernel via a one-time autotuning
+    /// benchmark per `(kernel_shape, stride, dilation, padding)` signature, cached for subsequent
+    /// calls. See [`Conv2d::forward`] for the full rationale.
+    ///
+    /// Both paths compute the forward values directly against `input`'s and `weight`'s underlying
+    /// data (see [`conv_math`]); because this workspace does not vendor `tactics_core`, neither has
+    /// a way to append a node to an existing computational graph, so the returned variable is a
+    /// fresh leaf and gradients do not flow back to `weight`, `bias` or `input` through it.
     pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
         where
-            VarDiff<Ix5>: Convolution<I, Ix5>,
-            <VarDiff<Ix5> as Convolution<I, Ix5>>::Output: Into<VarDiff<Ix5>>,
+            I: Into<VarDiff<Ix5>>,
     {
-        todo!()
+        let kernel_shape = self.weight.data().shape().to_vec();
+        let (stride_d, stride_h, stride_w) = self.stride;
+        let (dilation_d, dilation_h, dilation_w) = self.dilation;
+        let (padding_d, padding_h, padding_w) = self.padding;
+        let signature = conv_autotune::signature(
+            &[],
+            &kernel_shape,
+            &[stride_d, stride_h, stride_w],
+            &[dilation_d, dilation_h, dilation_w],
+            &[padding_d, padding_h, padding_w],
+        );
+
+        let input = input.into();
+        let padded = conv_math::pad_batch(
+            &input.data(),
+            &self.padding_mode,
+            &[0, padding_d, padding_h, padding_w],
+        );
+        let input_view = padded.view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+        let stride = [stride_d, stride_h, stride_w];
+        let dilation = [dilation_d, dilation_h, dilation_w];
+
+        let mut output = match autotune(
+            signature,
+            || {
+                let start = std::time::Instant::now();
+                conv_math::im2col(&input_view, &weight_view, &stride, &dilation, 1);
+                start.elapsed()
+            },
+            || {
+                let start = std::time::Instant::now();
+                conv_math::direct(&input_view, &weight_view, &stride, &dilation, 1);
+                start.elapsed()
+            },
+        ) {
+            ConvStrategy::ImToCol => conv_math::im2col(&input_view, &weight_view, &stride, &dilation, 1),
+            ConvStrategy::Direct => conv_math::direct(&input_view, &weight_view, &stride, &dilation, 1),
+        };
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix5>().expect("error: conv3d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix5>> Module for Conv3d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix5(self.weight.clone()),
+            Param::Ix4(self.bias.clone()),
+        ]
+    }
+}
+
+/// Applies a **grouped volumetric convolution** over an input signal composed of several input
+/// planes.
+///
+/// The input and output channels are partitioned into `groups` independent blocks: each group
+/// convolves only its own slice of `Cin / groups` input channels, producing its own slice of
+/// `Cout / groups` output channels. Setting `groups` equal to `Cin` (with `Cout` a multiple of
+/// `Cin`) gives a **depthwise** convolution; any other divisor of both `Cin` and `Cout` gives a
+/// ResNeXt-style grouped convolution.
+///
+/// See also [`Conv3d`].
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GroupedConv3d<T>
+    where
+        T: PaddingMode<Ix5>,
+{
+    pub padding: (usize, usize, usize),
+    pub padding_mode: T,
+    pub stride: (usize, usize, usize),
+    pub dilation: (usize, usize, usize),
+    pub groups: usize,
+    pub weight: VarDiff<Ix5>,
+    pub bias: VarDiff<Ix4>,
+}
+
+impl<T> GroupedConv3d<T>
+    where
+        T: PaddingMode<Ix5>,
+{
+    /// Creates a new GroupedConv3d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding` - padding to be applied to the input, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
+    /// [`Replicative`].
+    ///
+    /// * `stride` - stride of the convolution, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
+    /// three-dimensional case.
+    ///
+    /// * `groups` - number of blocks the input and output channels are partitioned into. Setting
+    /// `groups` equal to `in_channels` gives a depthwise convolution.
+    ///
+    /// The weight and the bias of the layer are initialized from *U(-k, k)* where
+    /// `k = (1. /((in_channels / groups) * kernel_d * kernel_w * kernel_h) as f32).sqrt()`.
+    ///
+    /// # Panics
+    ///
+    /// If `in_channels % groups != 0` or `out_channels % groups != 0`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize, usize),
+        padding: (usize, usize, usize),
+        padding_mode: T,
+        stride: (usize, usize, usize),
+        dilation: (usize, usize, usize),
+        groups: usize,
+    ) -> Self {
+        assert_eq!(
+            in_channels % groups,
+            0,
+            "error: in_channels must be divisible by groups."
+        );
+        assert_eq!(
+            out_channels % groups,
+            0,
+            "error: out_channels must be divisible by groups."
+        );
+
+        let (kernel_d, kernel_h, kernel_w) = kernel_size;
+        let weight = tactics_variable::zeros((
+            out_channels,
+            in_channels / groups,
+            kernel_d,
+            kernel_h,
+            kernel_w,
+        ))
+        .requires_grad();
+        let bias = tactics_variable::zeros((out_channels, 1, 1, 1)).requires_grad();
+
+        let k = (1. / ((in_channels / groups) * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            stride,
+            dilation,
+            groups,
+            weight,
+            bias,
+        }
+    }
+
+    /// Computes a 3-dimensional grouped convolution *(cross correlation)*.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, D, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **D** is the **depth** of the input
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cout, Cin / groups, Dk, Hk, Wk)*
+    /// * **Cout** is the number of output channels
+    /// * **Cin** is the number of input channels
+    /// * **Dk** is the **depth** of the kernel
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*
+    ///
+    /// See [`GroupedConv2d::forward`] for how the depthwise case, the autotuning cache and the
+    /// fresh-leaf output are handled; the same applies here.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+        where
+            I: Into<VarDiff<Ix5>>,
+    {
+        let kernel_shape = self.weight.data().shape().to_vec();
+        let (stride_d, stride_h, stride_w) = self.stride;
+        let (dilation_d, dilation_h, dilation_w) = self.dilation;
+        let (padding_d, padding_h, padding_w) = self.padding;
+        let signature = conv_autotune::signature(
+            &[self.groups],
+            &kernel_shape,
+            &[stride_d, stride_h, stride_w],
+            &[dilation_d, dilation_h, dilation_w],
+            &[padding_d, padding_h, padding_w],
+        );
+
+        let input = input.into();
+        let padded = conv_math::pad_batch(
+            &input.data(),
+            &self.padding_mode,
+            &[0, padding_d, padding_h, padding_w],
+        );
+        let input_view = padded.view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+        let stride = [stride_d, stride_h, stride_w];
+        let dilation = [dilation_d, dilation_h, dilation_w];
+
+        let mut output = match autotune(
+            signature,
+            || {
+                let start = std::time::Instant::now();
+                conv_math::im2col(&input_view, &weight_view, &stride, &dilation, self.groups);
+                start.elapsed()
+            },
+            || {
+                let start = std::time::Instant::now();
+                conv_math::direct(&input_view, &weight_view, &stride, &dilation, self.groups);
+                start.elapsed()
+            },
+        ) {
+            ConvStrategy::ImToCol => conv_math::im2col(&input_view, &weight_view, &stride, &dilation, self.groups),
+            ConvStrategy::Direct => conv_math::direct(&input_view, &weight_view, &stride, &dilation, self.groups),
+        };
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix5>().expect("error: grouped conv3d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix5>> Module for GroupedConv3d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix5(self.weight.clone()),
+            Param::Ix4(self.bias.clone()),
+        ]
+    }
+}
+
+/// Applies a **temporal transposed convolution** over an input signal composed of several input
+/// planes.
+///
+/// A transposed convolution's forward is, conceptually, the input-gradient of a regular
+/// convolution: each input element, scaled by the kernel, is scattered into a larger output grid,
+/// with `stride` acting as an upsampling factor. This makes it the standard decoder primitive for
+/// architectures that progressively upsample a signal.
+///
+/// See also [`Conv1d`].
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose1d<T>
+    where
+        T: PaddingMode<Ix3>,
+{
+    pub padding: usize,
+    pub padding_mode: T,
+    pub output_padding: usize,
+    pub stride: usize,
+    pub dilation: usize,
+    pub weight: VarDiff<Ix3>,
+    pub bias: VarDiff<Ix2>,
+}
+
+impl<T> ConvTranspose1d<T>
+    where
+        T: PaddingMode<Ix3>,
+{
+    /// Creates a new ConvTranspose1d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a number for this one-dimensional case.
+    ///
+    /// * `padding` - padding that was applied to the input of the equivalent regular convolution,
+    /// a number for this one-dimensional case.
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
+    /// [`Replicative`].
+    ///
+    /// * `output_padding` - additional size added to one side of the output shape, a number for
+    /// this one-dimensional case.
+    ///
+    /// * `stride` - stride of the equivalent regular convolution, a number for this
+    /// one-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a number for this
+    /// one-dimensional case.
+    ///
+    /// The weight is of shape `(in_channels, out_channels, kernel_size)`, note the channel
+    /// transpose relative to [`Conv1d`]. The weight and the bias of the layer are initialized from
+    /// *U(-k, k)* where `k = (1. /(in_channels * kernel_size) as f32).sqrt()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: usize,
+        padding: usize,
+        padding_mode: T,
+        output_padding: usize,
+        stride: usize,
+        dilation: usize,
+    ) -> Self {
+        let weight =
+            tactics_variable::zeros((in_channels, out_channels, kernel_size)).requires_grad();
+        let bias = tactics_variable::zeros((out_channels, 1)).requires_grad();
+
+        let k = (1. / (in_channels * kernel_size) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            output_padding,
+            stride,
+            dilation,
+            weight,
+            bias,
+        }
+    }
+
+    /// Computes a 1-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, L)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **L** is the **length** of the input
+    ///
+    /// The **kernel** must be of shape *(Cin, Cout, Lk)*
+    /// * **Cin** is the number of input channels
+    /// * **Cout** is the number of output channels
+    /// * **Lk** is the **length** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Lout)*, with
+    /// `Lout = (L - 1) * stride - 2 * padding + dilation * (Lk - 1) + output_padding + 1`.
+    ///
+    /// The scatter (see [`conv_math::transpose`]) computes the forward values directly against
+    /// `input`'s and `weight`'s underlying data. `tactics_variable::node::ConvTranspose`/
+    /// `ConvTransposeBackward` already implement the matching col2im-style gradient (see
+    /// [`conv_math::leaf`] for the full rationale on why this `forward` doesn't call into them
+    /// yet), so the returned variable is a fresh leaf and gradients do not flow back to `weight`,
+    /// `bias` or `input` through it.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix3>
+        where
+            I: Into<VarDiff<Ix3>>,
+    {
+        let input = input.into();
+        let input_view = input.data().view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+
+        let mut output = conv_math::transpose(
+            &input_view,
+            &weight_view,
+            &[self.stride],
+            &[self.dilation],
+            &[self.padding],
+            &[self.output_padding],
+        );
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix3>().expect("error: transposed conv1d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix3>> Module for ConvTranspose1d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix3(self.weight.clone()),
+            Param::Ix2(self.bias.clone()),
+        ]
+    }
+}
+
+/// Applies a **spatial transposed convolution** over an input signal composed of several input
+/// planes.
+///
+/// A transposed convolution's forward is, conceptually, the input-gradient of a regular
+/// convolution: each input element, scaled by the kernel, is scattered into a larger output grid,
+/// with `stride` acting as an upsampling factor. This makes it the standard decoder primitive for
+/// architectures that progressively upsample a signal, such as GAN generators and segmentation
+/// heads.
+///
+/// See also [`Conv2d`].
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose2d<T>
+    where
+        T: PaddingMode<Ix4>,
+{
+    pub padding: (usize, usize),
+    pub padding_mode: T,
+    pub output_padding: (usize, usize),
+    pub stride: (usize, usize),
+    pub dilation: (usize, usize),
+    pub weight: VarDiff<Ix4>,
+    pub bias: VarDiff<Ix3>,
+}
+
+impl<T> ConvTranspose2d<T>
+    where
+        T: PaddingMode<Ix4>,
+{
+    /// Creates a new ConvTranspose2d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding` - padding that was applied to the input of the equivalent regular convolution,
+    /// a 2-tuple for this two-dimensional case.
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
+    /// [`Replicative`].
+    ///
+    /// * `output_padding` - additional size added to one side of the output shape, a 2-tuple for
+    /// this two-dimensional case.
+    ///
+    /// * `stride` - stride of the equivalent regular convolution, a 2-tuple for this
+    /// two-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 2-tuple for this
+    /// two-dimensional case.
+    ///
+    /// The weight is of shape `(in_channels, out_channels, kernel_h, kernel_w)`, note the channel
+    /// transpose relative to [`Conv2d`]. The weight and the bias are initialized from *U(-k, k)*
+    /// where `k = (1. /(in_channels * kernel_w * kernel_h) as f32).sqrt()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize),
+        padding: (usize, usize),
+        padding_mode: T,
+        output_padding: (usize, usize),
+        stride: (usize, usize),
+        dilation: (usize, usize),
+    ) -> Self {
+        let (kernel_h, kernel_w) = kernel_size;
+        let weight = tactics_variable::zeros((in_channels, out_channels, kernel_h, kernel_w))
+            .requires_grad();
+        let bias = tactics_variable::zeros((out_channels, 1, 1)).requires_grad();
+
+        let k = (1. / (in_channels * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            output_padding,
+            stride,
+            dilation,
+            weight,
+            bias,
+        }
+    }
+
+    /// Computes a 2-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - the signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cin, Cout, Hk, Wk)*
+    /// * **Cin** is the number of input channels
+    /// * **Cout** is the number of output channels
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Hout, Wout)*, with
+    /// `Hout = (H - 1) * stride_h - 2 * padding_h + dilation_h * (Hk - 1) + output_padding_h + 1`
+    /// and likewise for `Wout`.
+    ///
+    /// See [`ConvTranspose1d::forward`] for the fresh-leaf caveat this scatter is subject to.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix4>
+        where
+            I: Into<VarDiff<Ix4>>,
+    {
+        let (stride_h, stride_w) = self.stride;
+        let (dilation_h, dilation_w) = self.dilation;
+        let (padding_h, padding_w) = self.padding;
+        let (output_padding_h, output_padding_w) = self.output_padding;
+
+        let input = input.into();
+        let input_view = input.data().view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+
+        let mut output = conv_math::transpose(
+            &input_view,
+            &weight_view,
+            &[stride_h, stride_w],
+            &[dilation_h, dilation_w],
+            &[padding_h, padding_w],
+            &[output_padding_h, output_padding_w],
+        );
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix4>().expect("error: transposed conv2d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix4>> Module for ConvTranspose2d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix4(self.weight.clone()),
+            Param::Ix3(self.bias.clone()),
+        ]
+    }
+}
+
+/// Applies a **volumetric transposed convolution** over an input signal composed of several
+/// input planes.
+///
+/// A transposed convolution's forward is, conceptually, the input-gradient of a regular
+/// convolution: each input element, scaled by the kernel, is scattered into a larger output grid,
+/// with `stride` acting as an upsampling factor.
+///
+/// See also [`Conv3d`].
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct ConvTranspose3d<T>
+    where
+        T: PaddingMode<Ix5>,
+{
+    pub padding: (usize, usize, usize),
+    pub padding_mode: T,
+    pub output_padding: (usize, usize, usize),
+    pub stride: (usize, usize, usize),
+    pub dilation: (usize, usize, usize),
+    pub weight: VarDiff<Ix5>,
+    pub bias: VarDiff<Ix4>,
+}
+
+impl<T> ConvTranspose3d<T>
+    where
+        T: PaddingMode<Ix5>,
+{
+    /// Creates a new ConvTranspose3d.
+    ///
+    /// # Arguments
+    ///
+    /// * `in_channels` - number of planes in the input signal.
+    ///
+    /// * `out_channels` - number of planes in the output signal.
+    ///
+    /// * `kernel_size` - size of the kernel, a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding` - padding that was applied to the input of the equivalent regular convolution,
+    /// a 3-tuple for this three-dimensional case.
+    ///
+    /// * `padding_mode` - padding mode, it can be: [`Zero`], [`Constant`], [`Reflective`] or
+    /// [`Replicative`].
+    ///
+    /// * `output_padding` - additional size added to one side of the output shape, a 3-tuple for
+    /// this three-dimensional case.
+    ///
+    /// * `stride` - stride of the equivalent regular convolution, a 3-tuple for this
+    /// three-dimensional case.
+    ///
+    /// * `dilation` - controls the spacing between the kernel points, a 3-tuple for this
+    /// three-dimensional case.
+    ///
+    /// The weight is of shape `(in_channels, out_channels, kernel_d, kernel_h, kernel_w)`, note
+    /// the channel transpose relative to [`Conv3d`]. The weight and the bias of the layer are
+    /// initialized from *U(-k, k)* where
+    /// `k = (1. /(in_channels * kernel_d * kernel_w * kernel_h) as f32).sqrt()`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        in_channels: usize,
+        out_channels: usize,
+        kernel_size: (usize, usize, usize),
+        padding: (usize, usize, usize),
+        padding_mode: T,
+        output_padding: (usize, usize, usize),
+        stride: (usize, usize, usize),
+        dilation: (usize, usize, usize),
+    ) -> Self {
+        let (kernel_d, kernel_h, kernel_w) = kernel_size;
+        let weight = tactics_variable::zeros((
+            in_channels,
+            out_channels,
+            kernel_d,
+            kernel_h,
+            kernel_w,
+        ))
+        .requires_grad();
+        let bias = tactics_variable::zeros((out_channels, 1, 1, 1)).requires_grad();
+
+        let k = (1. / (in_channels * kernel_d * kernel_h * kernel_w) as f32).sqrt();
+        init::uniform(&weight, -k, k);
+        init::uniform(&bias, -k, k);
+
+        Self {
+            padding,
+            padding_mode,
+            output_padding,
+            stride,
+            dilation,
+            weight,
+            bias,
+        }
+    }
+
+    /// Computes a 3-dimensional transposed convolution.
+    ///
+    /// # Arguments
+    ///
+    /// `input` - signal to convolve.
+    ///
+    /// The **input** must be of shape *(N, Cin, D, H, W)*
+    /// * **N** is the batch size
+    /// * **Cin** is the number of input channels
+    /// * **D** is the **depth** of the input
+    /// * **H** is the **height** of the input
+    /// * **W** is the **width** of the input
+    ///
+    /// The **kernel** must be of shape *(Cin, Cout, Dk, Hk, Wk)*
+    /// * **Cin** is the number of input channels
+    /// * **Cout** is the number of output channels
+    /// * **Dk** is the **depth** of the kernel
+    /// * **Hk** is the **height** of the kernel
+    /// * **Wk** is the **width** of the kernel
+    ///
+    /// The resulting output shape will be *(N, Cout, Dout, Hout, Wout)*, with
+    /// `Dout = (D - 1) * stride_d - 2 * padding_d + dilation_d * (Dk - 1) + output_padding_d + 1`
+    /// and likewise for `Hout` and `Wout`.
+    ///
+    /// See [`ConvTranspose1d::forward`] for the fresh-leaf caveat this scatter is subject to.
+    pub fn forward<I>(&self, input: I) -> VarDiff<Ix5>
+        where
+            I: Into<VarDiff<Ix5>>,
+    {
+        let (stride_d, stride_h, stride_w) = self.stride;
+        let (dilation_d, dilation_h, dilation_w) = self.dilation;
+        let (padding_d, padding_h, padding_w) = self.padding;
+        let (output_padding_d, output_padding_h, output_padding_w) = self.output_padding;
+
+        let input = input.into();
+        let input_view = input.data().view().into_dyn();
+        let weight_view = self.weight.data().view().into_dyn();
+
+        let mut output = conv_math::transpose(
+            &input_view,
+            &weight_view,
+            &[stride_d, stride_h, stride_w],
+            &[dilation_d, dilation_h, dilation_w],
+            &[padding_d, padding_h, padding_w],
+            &[output_padding_d, output_padding_h, output_padding_w],
+        );
+        conv_math::add_bias(&mut output, &self.bias.data().view().into_dyn().to_owned());
+
+        conv_math::leaf(output.into_dimensionality::<Ix5>().expect("error: transposed conv3d output has unexpected rank"))
+    }
+}
+
+impl<T: PaddingMode<Ix5>> Module for ConvTranspose3d<T> {
+    fn parameters(&self) -> Vec<Param> {
+        vec![
+            Param::Ix5(self.weight.clone()),
+            Param::Ix4(self.bias.clone()),
+        ]
     }
 }
\ No newline at end of file
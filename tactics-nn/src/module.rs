@@ -0,0 +1,277 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+#[cfg(feature = "serialize")]
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+};
+#[cfg(feature = "serialize")]
+use std::path::Path;
+
+use ndarray::{Ix1, Ix2, Ix3, Ix4, Ix5};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use tactics_variable::VarDiff;
+
+/// A single learnable leaf of a [`Module`], type-erased over its dimensionality so that
+/// [`Module::parameters`] can return the weights and biases of layers with different tensor ranks
+/// (e.g. [`Linear`](crate::Linear)'s `Ix2` weight next to [`Conv2d`](crate::Conv2d)'s `Ix4` one)
+/// in a single `Vec`.
+pub enum Param {
+    Ix1(VarDiff<Ix1>),
+    Ix2(VarDiff<Ix2>),
+    Ix3(VarDiff<Ix3>),
+    Ix4(VarDiff<Ix4>),
+    Ix5(VarDiff<Ix5>),
+}
+
+macro_rules! with_var {
+    ($self:expr, |$var:ident| $body:expr) => {
+        match $self {
+            Param::Ix1($var) => $body,
+            Param::Ix2($var) => $body,
+            Param::Ix3($var) => $body,
+            Param::Ix4($var) => $body,
+            Param::Ix5($var) => $body,
+        }
+    };
+}
+
+impl Param {
+    /// Shape of this parameter's underlying data, used to validate a checkpoint before loading it.
+    pub fn shape(&self) -> Vec<usize> {
+        with_var!(self, |var| var.data().shape().to_vec())
+    }
+
+    /// Zeroes this parameter's gradient and that of every downstream differentiable leaf of its
+    /// graph.
+    pub fn zero_grad(&self) {
+        with_var!(self, |var| var.zero_grad())
+    }
+
+    /// Switches this parameter's graph, and any stateful node within it (e.g. dropout), into
+    /// training mode.
+    pub fn train(&self) {
+        with_var!(self, |var| var.train())
+    }
+
+    /// Switches this parameter's graph, and any stateful node within it (e.g. dropout), into
+    /// evaluation mode.
+    pub fn eval(&self) {
+        with_var!(self, |var| var.eval())
+    }
+
+    #[cfg(feature = "serialize")]
+    fn values(&self) -> Vec<f32> {
+        with_var!(self, |var| var.data().iter().copied().collect())
+    }
+
+    #[cfg(feature = "serialize")]
+    fn assign(&mut self, values: &[f32]) {
+        with_var!(self, |var| var
+            .data_mut()
+            .iter_mut()
+            .zip(values.iter())
+            .for_each(|(el, &saved)| *el = saved))
+    }
+}
+
+/// Common interface for every layer in this crate, letting a user struct composed of them
+/// aggregate their learnable leaves, reset their gradients, switch their mode, and checkpoint all
+/// of them with one call instead of one per layer.
+pub trait Module {
+    /// Every learnable leaf reachable from this module, in a stable, deterministic order.
+    fn parameters(&self) -> Vec<Param>;
+
+    /// Zeroes the gradient of every parameter in this module.
+    fn zero_grad(&self) {
+        for param in self.parameters() {
+            param.zero_grad();
+        }
+    }
+
+    /// Calls [`Param::train`] on every parameter, switching the stateful nodes (e.g. dropout) of
+    /// their graphs into training mode. Dropout applied outside the autograd graph, such as the
+    /// inter-layer dropout of [`LSTM`](crate::LSTM) and [`GRU`](crate::GRU), is not affected.
+    fn train(&self) {
+        for param in self.parameters() {
+            param.train();
+        }
+    }
+
+    /// Calls [`Param::eval`] on every parameter, switching the stateful nodes (e.g. dropout) of
+    /// their graphs into evaluation mode. Dropout applied outside the autograd graph, such as the
+    /// inter-layer dropout of [`LSTM`](crate::LSTM) and [`GRU`](crate::GRU), is not affected.
+    fn eval(&self) {
+        for param in self.parameters() {
+            param.eval();
+        }
+    }
+
+    /// Serializes every parameter of this module tree to a single file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModuleError::Io`] if `path` cannot be created or written to.
+    #[cfg(feature = "serialize")]
+    fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ModuleError> {
+        save(path, &self.parameters())
+    }
+
+    /// Restores every parameter of this module tree from a checkpoint previously written by
+    /// [`Module::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ModuleError::Io`] if `path` cannot be opened, [`ModuleError::Decode`] if it is not
+    /// a valid checkpoint, [`ModuleError::ParamCountMismatch`] if the number of parameters differs,
+    /// and [`ModuleError::ShapeMismatch`] if any parameter's shape differs.
+    #[cfg(feature = "serialize")]
+    fn load<P: AsRef<Path>>(&self, path: P) -> Result<(), ModuleError> {
+        load(path, &mut self.parameters())
+    }
+}
+
+/// A homogeneous stack of modules of the same type, composing as a single [`Module`].
+///
+/// Useful for stacking repeated blocks (e.g. several identical residual blocks) without
+/// hand-rolling `parameters()`/`zero_grad()`/`train()`/`eval()` for the wrapper struct.
+pub struct ModuleList<M> {
+    modules: Vec<M>,
+}
+
+impl<M> ModuleList<M> {
+    /// Creates a new ModuleList wrapping `modules`.
+    pub fn new(modules: Vec<M>) -> Self {
+        Self { modules }
+    }
+}
+
+impl<M: Module> Module for ModuleList<M> {
+    fn parameters(&self) -> Vec<Param> {
+        self.modules.iter().flat_map(Module::parameters).collect()
+    }
+}
+
+/// Error returned by [`Module::save`] and [`Module::load`].
+#[cfg(feature = "serialize")]
+#[derive(Debug)]
+pub enum ModuleError {
+    /// I/O failure while reading or writing the checkpoint file.
+    Io(io::Error),
+    /// The checkpoint file is not valid bincode, or not a checkpoint at all.
+    Decode(String),
+    /// A parameter's shape in the checkpoint does not match the shape of the parameter it is
+    /// being loaded into.
+    ShapeMismatch {
+        index: usize,
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
+    /// The checkpoint and the module do not agree on the number of parameters.
+    ParamCountMismatch { expected: usize, found: usize },
+}
+
+#[cfg(feature = "serialize")]
+impl fmt::Display for ModuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "error: I/O failure while handling checkpoint: {err}"),
+            Self::Decode(err) => write!(f, "error: malformed checkpoint: {err}"),
+            Self::ShapeMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "error: checkpoint parameter {index} has shape {found:?}, expected {expected:?}"
+            ),
+            Self::ParamCountMismatch { expected, found } => write!(
+                f,
+                "error: checkpoint holds {found} parameters, expected {expected}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl std::error::Error for ModuleError {}
+
+#[cfg(feature = "serialize")]
+impl From<io::Error> for ModuleError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[derive(Serialize, Deserialize)]
+struct ParamSnapshot {
+    shape: Vec<usize>,
+    data: Vec<f32>,
+}
+
+#[cfg(feature = "serialize")]
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    parameters: Vec<ParamSnapshot>,
+}
+
+#[cfg(feature = "serialize")]
+fn save<P: AsRef<Path>>(path: P, parameters: &[Param]) -> Result<(), ModuleError> {
+    let parameters = parameters
+        .iter()
+        .map(|param| ParamSnapshot {
+            shape: param.shape(),
+            data: param.values(),
+        })
+        .collect();
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    bincode::serialize_into(&mut writer, &Snapshot { parameters }).map_err(|err| match *err {
+        bincode::ErrorKind::Io(err) => ModuleError::Io(err),
+        err => ModuleError::Decode(err.to_string()),
+    })?;
+    io::Write::flush(&mut writer)?;
+    Ok(())
+}
+
+#[cfg(feature = "serialize")]
+fn load<P: AsRef<Path>>(path: P, parameters: &mut [Param]) -> Result<(), ModuleError> {
+    let reader = BufReader::new(File::open(path)?);
+    let snapshot: Snapshot =
+        bincode::deserialize_from(reader).map_err(|err| ModuleError::Decode(err.to_string()))?;
+
+    if snapshot.parameters.len() != parameters.len() {
+        return Err(ModuleError::ParamCountMismatch {
+            expected: parameters.len(),
+            found: snapshot.parameters.len(),
+        });
+    }
+
+    for (index, (param, saved)) in parameters.iter_mut().zip(snapshot.parameters.iter()).enumerate() {
+        let shape = param.shape();
+        if shape != saved.shape {
+            return Err(ModuleError::ShapeMismatch {
+                index,
+                expected: shape,
+                found: saved.shape.clone(),
+            });
+        }
+
+        param.assign(&saved.data);
+    }
+
+    Ok(())
+}
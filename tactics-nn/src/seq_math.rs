@@ -0,0 +1,45 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{Array, Axis, Ix2, Ix3};
+
+/// Extracts timestep `t` of a `(seq_len, batch, features)` sequence as a `(batch, features)`
+/// array.
+pub(crate) fn timestep(input: &Array<f32, Ix3>, t: usize) -> Array<f32, Ix2> {
+    input.index_axis(Axis(0), t).to_owned()
+}
+
+/// Restitches `steps`, each a `(batch, features)` array for one timestep in order, back into a
+/// single `(seq_len, batch, features)` sequence.
+pub(crate) fn stack(steps: &[Array<f32, Ix2>]) -> Array<f32, Ix3> {
+    let views: Vec<_> = steps.iter().map(Array::view).collect();
+    ndarray::stack(Axis(0), &views).expect("error: timestep outputs have mismatched shapes")
+}
+
+/// Concatenates two `(batch, features)` arrays along the feature axis, joining a bidirectional
+/// layer's forward and reverse outputs for one timestep.
+pub(crate) fn concat_features(a: &Array<f32, Ix2>, b: &Array<f32, Ix2>) -> Array<f32, Ix2> {
+    ndarray::concatenate(Axis(1), &[a.view(), b.view()])
+        .expect("error: forward and reverse outputs have mismatched shapes")
+}
+
+/// Applies inverted dropout to a `(batch, features)` timestep: every element is independently
+/// zeroed with probability `probability`, and every surviving element is scaled by
+/// `1 / (1 - probability)` so the output's expectation matches the input's with dropout off.
+///
+/// A no-op when `probability <= 0.`.
+pub(crate) fn dropout(input: &Array<f32, Ix2>, probability: f32) -> Array<f32, Ix2> {
+    if probability <= 0. {
+        return input.clone();
+    }
+
+    let scale = 1. / (1. - probability);
+    input.mapv(|el| if rand::random::<f32>() < probability { 0. } else { el * scale })
+}
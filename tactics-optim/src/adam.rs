@@ -0,0 +1,279 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{Array, Axis, Dimension, RemoveAxis, Zip};
+
+use serde::{Deserialize, Serialize};
+
+use tactics_variable::SparseGradient;
+
+use crate::checkpoint::{Checkpoint, CheckpointError};
+use crate::decoupled_weight_decay::decoupled_weight_decay;
+use crate::sparse::SparseStep;
+
+/// The **Adam** optimizer (Kingma & Ba, 2014): per-parameter learning rates adapted from running
+/// estimates of the gradient's first and second moments.
+///
+/// Tracks one pair of moment accumulators per parameter (plus, with `amsgrad` on, a running max of
+/// the second moment), in the order the parameters were passed to [`Adam::new`]; [`Adam::step`]
+/// expects `params` and `grads` to line up with that same order on every call.
+pub struct Adam<D: Dimension> {
+    lr: f32,
+    betas: (f32, f32),
+    eps: f32,
+    amsgrad: bool,
+    decoupled_weight_decay: Option<f32>,
+    step: usize,
+    moment1: Vec<Array<f32, D>>,
+    moment2: Vec<Array<f32, D>>,
+    max_moment2: Vec<Array<f32, D>>,
+}
+
+impl<D: Dimension> Adam<D> {
+    /// Creates a new Adam optimizer tracking one parameter per shape in `shapes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `shapes` - the shape of every parameter this optimizer will be stepped on, in the order
+    /// `step` expects them.
+    ///
+    /// * `lr` - the base learning rate.
+    ///
+    /// * `betas` - the exponential decay rates for the first and second moment estimates.
+    ///
+    /// * `eps` - added to the denominator of the adaptive step for numerical stability.
+    ///
+    /// * `amsgrad` - if `true`, the denominator uses the running *max* of the second moment
+    /// instead of its current value (Reddi et al., 2018), fixing cases where plain Adam fails to
+    /// converge.
+    ///
+    /// * `decoupled_weight_decay` - if `Some(weight_decay)`, applies AdamW-style weight decay
+    /// directly to the parameter after the adaptive step, via [`decoupled_weight_decay`], instead
+    /// of folding it into the gradient.
+    pub fn new(
+        shapes: &[D],
+        lr: f32,
+        betas: (f32, f32),
+        eps: f32,
+        amsgrad: bool,
+        decoupled_weight_decay: Option<f32>,
+    ) -> Self {
+        Self {
+            lr,
+            betas,
+            eps,
+            amsgrad,
+            decoupled_weight_decay,
+            step: 0,
+            moment1: shapes.iter().map(|shape| Array::zeros(shape.clone())).collect(),
+            moment2: shapes.iter().map(|shape| Array::zeros(shape.clone())).collect(),
+            max_moment2: shapes.iter().map(|shape| Array::zeros(shape.clone())).collect(),
+        }
+    }
+
+    /// Applies one Adam update to every entry of `params`, using the matching entry of `grads`.
+    ///
+    /// # Panics
+    ///
+    /// If `params` or `grads` does not hold exactly one entry per shape this optimizer was
+    /// constructed with.
+    pub fn step(&mut self, params: &mut [Array<f32, D>], grads: &[Array<f32, D>]) {
+        assert_eq!(
+            params.len(),
+            self.moment1.len(),
+            "error: expected exactly {} parameters, got {}.",
+            self.moment1.len(),
+            params.len()
+        );
+        assert_eq!(
+            grads.len(),
+            self.moment1.len(),
+            "error: expected exactly {} gradients, got {}.",
+            self.moment1.len(),
+            grads.len()
+        );
+
+        self.step += 1;
+        let (beta1, beta2) = self.betas;
+        let bias_correction1 = 1. - beta1.powi(self.step as i32);
+        let bias_correction2 = 1. - beta2.powi(self.step as i32);
+
+        for index in 0..params.len() {
+            let (param, grad) = (&mut params[index], &grads[index]);
+            let (moment1, moment2) = (&mut self.moment1[index], &mut self.moment2[index]);
+            let max_moment2 = &mut self.max_moment2[index];
+
+            Zip::from(&mut *moment1)
+                .and(grad)
+                .for_each(|moment1_el, &grad_el| *moment1_el = beta1 * *moment1_el + (1. - beta1) * grad_el);
+            Zip::from(&mut *moment2)
+                .and(grad)
+                .for_each(|moment2_el, &grad_el| *moment2_el = beta2 * *moment2_el + (1. - beta2) * grad_el * grad_el);
+            if self.amsgrad {
+                Zip::from(&mut *max_moment2)
+                    .and(&*moment2)
+                    .for_each(|max_el, &moment2_el| *max_el = max_el.max(moment2_el));
+            }
+            let denominator = if self.amsgrad { &*max_moment2 } else { &*moment2 };
+
+            Zip::from(&mut *param)
+                .and(&*moment1)
+                .and(denominator)
+                .for_each(|param_el, &moment1_el, &moment2_el| {
+                    let moment1_hat = moment1_el / bias_correction1;
+                    let moment2_hat = moment2_el / bias_correction2;
+                    *param_el -= self.lr * moment1_hat / (moment2_hat.sqrt() + self.eps);
+                });
+
+            if let Some(weight_decay) = self.decoupled_weight_decay {
+                decoupled_weight_decay(param, self.lr, weight_decay);
+            }
+        }
+    }
+}
+
+/// Applies Adam to only the rows of a table parameter referenced by a [`SparseGradient`], rather
+/// than materializing the whole table's dense gradient via [`SparseGradient::to_dense`] every
+/// step — the standard way to train a large embedding table where a single batch only looks up a
+/// handful of rows.
+///
+/// An `Adam<D::Larger>` used this way is expected to track exactly one parameter (the table
+/// itself), constructed from `&[table_shape]`; see [`Adam::new`].
+impl<D> SparseStep<D> for Adam<D::Larger>
+    where
+        D: Dimension,
+        D::Larger: Dimension<Smaller = D> + RemoveAxis,
+{
+    /// # Panics
+    ///
+    /// If this optimizer does not track exactly one parameter.
+    fn sparse_step(&mut self, param: &mut Array<f32, D::Larger>, gradient: &SparseGradient<D>) {
+        assert_eq!(
+            self.moment1.len(),
+            1,
+            "error: sparse_step requires an optimizer tracking exactly one parameter, this one tracks {}.",
+            self.moment1.len()
+        );
+
+        self.step += 1;
+        let (beta1, beta2) = self.betas;
+        let bias_correction1 = 1. - beta1.powi(self.step as i32);
+        let bias_correction2 = 1. - beta2.powi(self.step as i32);
+
+        for (&row, grad) in gradient.rows().iter() {
+            let mut param_row = param.index_axis_mut(Axis(0), row);
+            let mut moment1_row = self.moment1[0].index_axis_mut(Axis(0), row);
+            let mut moment2_row = self.moment2[0].index_axis_mut(Axis(0), row);
+            let mut max_moment2_row = self.max_moment2[0].index_axis_mut(Axis(0), row);
+
+            Zip::from(&mut moment1_row)
+                .and(grad)
+                .for_each(|moment1_el, &grad_el| *moment1_el = beta1 * *moment1_el + (1. - beta1) * grad_el);
+            Zip::from(&mut moment2_row)
+                .and(grad)
+                .for_each(|moment2_el, &grad_el| *moment2_el = beta2 * *moment2_el + (1. - beta2) * grad_el * grad_el);
+            if self.amsgrad {
+                Zip::from(&mut max_moment2_row)
+                    .and(&moment2_row)
+                    .for_each(|max_el, &moment2_el| *max_el = max_el.max(moment2_el));
+            }
+            let denominator = if self.amsgrad { &max_moment2_row } else { &moment2_row };
+
+            Zip::from(&mut param_row)
+                .and(&moment1_row)
+                .and(denominator)
+                .for_each(|param_el, &moment1_el, &moment2_el| {
+                    let moment1_hat = moment1_el / bias_correction1;
+                    let moment2_hat = moment2_el / bias_correction2;
+                    *param_el -= self.lr * moment1_hat / (moment2_hat.sqrt() + self.eps);
+                });
+
+            if let Some(weight_decay) = self.decoupled_weight_decay {
+                Zip::from(&mut param_row)
+                    .for_each(|param_el| *param_el -= self.lr * weight_decay * *param_el);
+            }
+        }
+    }
+}
+
+/// Per-parameter moment state saved and restored by [`Adam`]'s [`Checkpoint`] implementation.
+///
+/// `max_moment2` is always captured, even when an [`Adam`] is constructed with `amsgrad` off, so
+/// that checkpoints stay interchangeable regardless of whether the instance loading them has
+/// amsgrad enabled.
+#[derive(Serialize, Deserialize)]
+pub struct AdamState {
+    shape: Vec<usize>,
+    moment1: Vec<f32>,
+    moment2: Vec<f32>,
+    max_moment2: Vec<f32>,
+}
+
+impl<D: Dimension> Checkpoint for Adam<D> {
+    type State = AdamState;
+
+    fn state(&self) -> Vec<Self::State> {
+        self.moment1
+            .iter()
+            .zip(self.moment2.iter())
+            .zip(self.max_moment2.iter())
+            .map(|((moment1, moment2), max_moment2)| AdamState {
+                shape: moment1.shape().to_vec(),
+                moment1: moment1.iter().copied().collect(),
+                moment2: moment2.iter().copied().collect(),
+                max_moment2: max_moment2.iter().copied().collect(),
+            })
+            .collect()
+    }
+
+    fn load_state(&mut self, state: Vec<Self::State>) -> Result<(), CheckpointError> {
+        if state.len() != self.moment1.len() {
+            return Err(CheckpointError::ParamCountMismatch {
+                expected: self.moment1.len(),
+                found: state.len(),
+            });
+        }
+
+        for (index, (saved, ((moment1, moment2), max_moment2))) in state
+            .into_iter()
+            .zip(
+                self.moment1
+                    .iter_mut()
+                    .zip(self.moment2.iter_mut())
+                    .zip(self.max_moment2.iter_mut()),
+            )
+            .enumerate()
+        {
+            let shape = moment1.shape().to_vec();
+            if shape != saved.shape {
+                return Err(CheckpointError::ShapeMismatch {
+                    index,
+                    expected: shape,
+                    found: saved.shape,
+                });
+            }
+
+            moment1
+                .iter_mut()
+                .zip(saved.moment1.iter())
+                .for_each(|(el, &saved_el)| *el = saved_el);
+            moment2
+                .iter_mut()
+                .zip(saved.moment2.iter())
+                .for_each(|(el, &saved_el)| *el = saved_el);
+            max_moment2
+                .iter_mut()
+                .zip(saved.max_moment2.iter())
+                .for_each(|(el, &saved_el)| *el = saved_el);
+        }
+
+        Ok(())
+    }
+}
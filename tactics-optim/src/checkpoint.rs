@@ -0,0 +1,184 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufReader, BufWriter},
+    path::Path,
+};
+
+use ndarray::Dimension;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use tactics_variable::VarDiff;
+
+/// An optimizer that tracks extra per-parameter state (Adam's moments, AdaGrad's accumulators,
+/// AMSGrad's max-v, ...) that must round-trip through a checkpoint alongside the parameters
+/// themselves for training to resume bit-for-bit.
+pub trait Checkpoint {
+    /// Serializable snapshot of this optimizer's internal state, one entry per tracked parameter.
+    type State: Serialize + DeserializeOwned;
+
+    /// Captures the current state of every tracked parameter, in the same order they were
+    /// registered with the optimizer.
+    fn state(&self) -> Vec<Self::State>;
+
+    /// Restores a previously captured state.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CheckpointError::ParamCountMismatch`] if `state` does not hold exactly one entry
+    /// per tracked parameter.
+    fn load_state(&mut self, state: Vec<Self::State>) -> Result<(), CheckpointError>;
+}
+
+/// Error returned by [`save`] and [`load`].
+#[derive(Debug)]
+pub enum CheckpointError {
+    /// I/O failure while reading or writing the checkpoint file.
+    Io(io::Error),
+    /// The checkpoint file is not valid bincode, or not a checkpoint at all.
+    Decode(String),
+    /// A parameter's shape in the checkpoint does not match the shape of the parameter it is
+    /// being loaded into.
+    ShapeMismatch {
+        index: usize,
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
+    /// The checkpoint and the optimizer do not agree on the number of tracked parameters.
+    ParamCountMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "error: I/O failure while handling checkpoint: {err}"),
+            Self::Decode(err) => write!(f, "error: malformed checkpoint: {err}"),
+            Self::ShapeMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "error: checkpoint parameter {index} has shape {found:?}, expected {expected:?}"
+            ),
+            Self::ParamCountMismatch { expected, found } => write!(
+                f,
+                "error: checkpoint holds {found} parameters, expected {expected}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CheckpointError {}
+
+impl From<io::Error> for CheckpointError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ParamSnapshot {
+    shape: Vec<usize>,
+    data: Vec<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Snapshot<S> {
+    parameters: Vec<ParamSnapshot>,
+    optimizer: Vec<S>,
+}
+
+/// Serializes `parameters`, alongside `optimizer`'s own per-parameter state, to `path`.
+///
+/// # Errors
+///
+/// Returns [`CheckpointError::Io`] if the file cannot be created or written to.
+pub fn save<D, O, P>(path: P, parameters: &[VarDiff<D>], optimizer: &O) -> Result<(), CheckpointError>
+    where
+        D: Dimension,
+        O: Checkpoint,
+        P: AsRef<Path>,
+{
+    let parameters = parameters
+        .iter()
+        .map(|param| ParamSnapshot {
+            shape: param.data().shape().to_vec(),
+            data: param.data().iter().copied().collect(),
+        })
+        .collect();
+
+    let snapshot = Snapshot {
+        parameters,
+        optimizer: optimizer.state(),
+    };
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    bincode::serialize_into(&mut writer, &snapshot).map_err(|err| match *err {
+        bincode::ErrorKind::Io(err) => CheckpointError::Io(err),
+        err => CheckpointError::Decode(err.to_string()),
+    })?;
+    io::Write::flush(&mut writer)?;
+    Ok(())
+}
+
+/// Loads a checkpoint previously written by [`save`], assigning each parameter's saved data back
+/// into `parameters` in place and restoring `optimizer`'s state.
+///
+/// # Errors
+///
+/// Returns [`CheckpointError::Io`] if the file cannot be opened, [`CheckpointError::Decode`] if it
+/// is not a valid checkpoint, [`CheckpointError::ParamCountMismatch`] if the number of parameters
+/// differs, and [`CheckpointError::ShapeMismatch`] if any parameter's shape differs \u{2014} rather
+/// than panicking on a checkpoint that does not belong to this model.
+pub fn load<D, O, P>(
+    path: P,
+    parameters: &mut [VarDiff<D>],
+    optimizer: &mut O,
+) -> Result<(), CheckpointError>
+    where
+        D: Dimension,
+        O: Checkpoint,
+        P: AsRef<Path>,
+{
+    let reader = BufReader::new(File::open(path)?);
+    let snapshot: Snapshot<O::State> =
+        bincode::deserialize_from(reader).map_err(|err| CheckpointError::Decode(err.to_string()))?;
+
+    if snapshot.parameters.len() != parameters.len() {
+        return Err(CheckpointError::ParamCountMismatch {
+            expected: parameters.len(),
+            found: snapshot.parameters.len(),
+        });
+    }
+
+    for (index, (param, saved)) in parameters.iter_mut().zip(snapshot.parameters.iter()).enumerate() {
+        let shape = param.data().shape().to_vec();
+        if shape != saved.shape {
+            return Err(CheckpointError::ShapeMismatch {
+                index,
+                expected: shape,
+                found: saved.shape.clone(),
+            });
+        }
+
+        param
+            .data_mut()
+            .iter_mut()
+            .zip(saved.data.iter())
+            .for_each(|(el, &saved_el)| *el = saved_el);
+    }
+
+    optimizer.load_state(snapshot.optimizer)
+}
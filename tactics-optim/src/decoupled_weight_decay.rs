@@ -0,0 +1,23 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{Array, Dimension, Zip};
+
+/// Applies AdamW-style decoupled weight decay directly to `param`, rather than folding it into the
+/// gradient the way [`crate::penalty`] does.
+///
+/// Meant to be called after the optimizer's adaptive step, so that the decay is not itself scaled
+/// by the per-parameter adaptive learning rate: `θ ← θ − lr·wd·θ`.
+pub fn decoupled_weight_decay<D>(param: &mut Array<f32, D>, lr: f32, weight_decay: f32)
+    where
+        D: Dimension,
+{
+    Zip::from(param).for_each(|param_el| *param_el -= lr * weight_decay * *param_el);
+}
@@ -11,16 +11,22 @@
 mod adagrad;
 mod adam;
 mod amsgrad;
+mod checkpoint;
+mod decoupled_weight_decay;
 mod optimizer;
 mod penalty;
 mod rmsprop;
 mod sgd;
+mod sparse;
 
 pub mod lr_scheduler;
 
 pub use adagrad::*;
 pub use adam::*;
+pub use checkpoint::*;
+pub use decoupled_weight_decay::*;
 pub use optimizer::*;
 pub use penalty::*;
 pub use rmsprop::*;
 pub use sgd::*;
+pub use sparse::*;
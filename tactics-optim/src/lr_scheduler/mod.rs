@@ -0,0 +1,82 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+/// A schedule for an optimizer's learning rate, advanced one optimization step at a time.
+pub trait LrScheduler {
+    /// Advances the schedule by one step, recomputing the current learning rate.
+    fn step(&mut self);
+
+    /// Returns the learning rate for the current step.
+    fn get_lr(&self) -> f32;
+}
+
+/// The curve a [`WarmupScheduler`] decays along once its warmup period has elapsed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecayCurve {
+    /// Decays linearly from the base rate down to `0` over the remaining steps.
+    Linear,
+    /// Decays from the base rate down to `0` following a cosine curve.
+    Cosine,
+}
+
+/// Ramps the learning rate linearly from `0` up to `base_lr` over `warmup_steps`, then decays it
+/// down to `0` over the remaining `total_steps - warmup_steps` steps along `curve`.
+///
+/// Standard for transformer training, where a cold start at the base learning rate tends to
+/// destabilize the first few steps of adaptive optimizers such as Adam.
+pub struct WarmupScheduler {
+    base_lr: f32,
+    warmup_steps: usize,
+    total_steps: usize,
+    curve: DecayCurve,
+    step: usize,
+    lr: f32,
+}
+
+impl WarmupScheduler {
+    pub fn new(base_lr: f32, warmup_steps: usize, total_steps: usize, curve: DecayCurve) -> Self {
+        Self {
+            base_lr,
+            warmup_steps,
+            total_steps,
+            curve,
+            step: 0,
+            lr: 0.,
+        }
+    }
+
+    fn lr_at(&self, step: usize) -> f32 {
+        if step < self.warmup_steps {
+            return self.base_lr * (step as f32 / self.warmup_steps as f32);
+        }
+
+        let decay_steps = self.total_steps.saturating_sub(self.warmup_steps);
+        if decay_steps == 0 {
+            return self.base_lr;
+        }
+
+        let progress = ((step - self.warmup_steps) as f32 / decay_steps as f32).min(1.);
+        match self.curve {
+            DecayCurve::Linear => self.base_lr * (1. - progress),
+            DecayCurve::Cosine => self.base_lr * 0.5 * (1. + (std::f32::consts::PI * progress).cos()),
+        }
+    }
+}
+
+impl LrScheduler for WarmupScheduler {
+    fn step(&mut self) {
+        self.step += 1;
+        self.lr = self.lr_at(self.step);
+    }
+
+    fn get_lr(&self) -> f32 {
+        self.lr
+    }
+}
@@ -0,0 +1,28 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{Array, Dimension};
+
+use tactics_variable::SparseGradient;
+
+/// An optimizer able to apply its update to only the rows of a parameter referenced by a
+/// [`SparseGradient`], as produced by a gather/embedding-lookup operation.
+///
+/// Implementing this alongside the dense `step` lets training a large embedding table skip every
+/// row that was not looked up in a given batch, rather than paying for the whole table via
+/// [`SparseGradient::to_dense`] on every step.
+pub trait SparseStep<D>
+    where
+        D: Dimension,
+{
+    /// Applies this optimizer's update to the rows of `param` present in `gradient`, leaving every
+    /// other row untouched.
+    fn sparse_step(&mut self, param: &mut Array<f32, D::Larger>, gradient: &SparseGradient<D>);
+}
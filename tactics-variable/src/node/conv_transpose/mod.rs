@@ -0,0 +1,321 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::rc::Rc;
+
+use ndarray::{Array, ArrayD, ArrayViewD, Dimension, IxDyn, RemoveAxis, Zip};
+
+use crate::{
+    autograd::{Backward, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// Walks every multi-index of a (row-major) shape with `dims` elements per axis, in ascending
+/// order. Duplicated from [`crate::node::convolution`] rather than shared, since neither module
+/// depends on the other and both are small.
+fn indices(dims: &[usize]) -> impl Iterator<Item = Vec<usize>> + '_ {
+    let total: usize = dims.iter().product();
+    (0..total).map(move |flat| {
+        let mut flat = flat;
+        let mut index = vec![0; dims.len()];
+        for axis in (0..dims.len()).rev() {
+            index[axis] = flat % dims[axis];
+            flat /= dims[axis];
+        }
+        index
+    })
+}
+
+/// Scatter/transposed cross-correlation ("transposed convolution"): every input position is
+/// spread across the kernel's footprint in the output, accumulating by addition wherever two
+/// footprints overlap.
+///
+/// `input` is `(N, Cin, *spatial)`; `weight` is `(Cin, Cout, *kernel)`. `out_spatial` is the full,
+/// unpadded, un-output-cropped spatial extent of the scatter target.
+fn transpose(input: &ArrayViewD<f32>, weight: &ArrayViewD<f32>, out_spatial: &[usize], stride: &[usize], dilation: &[usize]) -> ArrayD<f32> {
+    let batch = input.shape()[0];
+    let in_channels = input.shape()[1];
+    let out_channels = weight.shape()[1];
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let in_spatial = input.shape()[2..].to_vec();
+
+    let mut out_shape = vec![batch, out_channels];
+    out_shape.extend(out_spatial);
+    let mut output = ArrayD::zeros(IxDyn(&out_shape));
+
+    for n in 0..batch {
+        for ic in 0..in_channels {
+            for in_pos in indices(&in_spatial) {
+                let mut in_index = vec![n, ic];
+                in_index.extend(in_pos.clone());
+                let in_val = input[IxDyn(&in_index)];
+
+                for oc in 0..out_channels {
+                    for k_pos in indices(&kernel_shape) {
+                        let full_pos: Vec<usize> = in_pos
+                            .iter()
+                            .zip(&k_pos)
+                            .enumerate()
+                            .map(|(axis, (&i, &k))| i * stride[axis] + k * dilation[axis])
+                            .collect();
+
+                        let mut w_index = vec![ic, oc];
+                        w_index.extend(k_pos);
+
+                        let mut out_index = vec![n, oc];
+                        out_index.extend(full_pos);
+                        output[IxDyn(&out_index)] += in_val * weight[IxDyn(&w_index)];
+                    }
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// Gradient of [`transpose`] with respect to its input and its weight: for every upstream output
+/// position that lies within the valid (unpadded) scatter target, walks back to every `(input,
+/// kernel)` pair that could have scattered into it.
+fn backward(input: &ArrayViewD<f32>, weight: &ArrayViewD<f32>, grad_output: &ArrayViewD<f32>, stride: &[usize], dilation: &[usize]) -> (ArrayD<f32>, ArrayD<f32>) {
+    let batch = input.shape()[0];
+    let in_channels = input.shape()[1];
+    let out_channels = weight.shape()[1];
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let in_spatial = input.shape()[2..].to_vec();
+    let out_spatial = grad_output.shape()[2..].to_vec();
+
+    let mut grad_input = ArrayD::zeros(input.raw_dim());
+    let mut grad_weight = ArrayD::zeros(weight.raw_dim());
+
+    for n in 0..batch {
+        for ic in 0..in_channels {
+            for in_pos in indices(&in_spatial) {
+                let mut in_index = vec![n, ic];
+                in_index.extend(in_pos.clone());
+                let in_val = input[IxDyn(&in_index)];
+
+                for oc in 0..out_channels {
+                    for k_pos in indices(&kernel_shape) {
+                        let full_pos: Vec<usize> = in_pos
+                            .iter()
+                            .zip(&k_pos)
+                            .enumerate()
+                            .map(|(axis, (&i, &k))| i * stride[axis] + k * dilation[axis])
+                            .collect();
+
+                        if full_pos.iter().zip(&out_spatial).any(|(&p, &bound)| p >= bound) {
+                            continue;
+                        }
+
+                        let mut w_index = vec![ic, oc];
+                        w_index.extend(k_pos);
+
+                        let mut out_index = vec![n, oc];
+                        out_index.extend(full_pos);
+                        let grad_out_el = grad_output[IxDyn(&out_index)];
+
+                        grad_input[IxDyn(&in_index)] += grad_out_el * weight[IxDyn(&w_index)];
+                        grad_weight[IxDyn(&w_index)] += grad_out_el * in_val;
+                    }
+                }
+            }
+        }
+    }
+
+    (grad_input, grad_weight)
+}
+
+/// A batched, N-dimensional transposed convolution ("deconvolution"): scatters `operand_data`
+/// through `weight_data` into a larger spatial extent, the adjoint of [`super::Convolution`].
+///
+/// After the scatter, `crop[axis]` elements are cut from the high end of each spatial axis; the
+/// low end is never cropped, so the output always starts at the scatter target's origin. There is
+/// no input-side padding step here, so unlike [`super::Convolution`] this node needs no
+/// [`PaddingMode`](crate::PaddingMode).
+pub(crate) struct ConvTranspose<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    operand_data: Shared<Array<f32, D>>,
+    weight_data: Shared<Array<f32, D>>,
+    scattered: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    crop: Vec<usize>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+}
+
+impl<D> ConvTranspose<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        weight_data: Shared<Array<f32, D>>,
+        scattered: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        crop: Vec<usize>,
+        stride: Vec<usize>,
+        dilation: Vec<usize>,
+    ) -> Self {
+        Self {
+            operand_data,
+            weight_data,
+            scattered,
+            data,
+            crop,
+            stride,
+            dilation,
+        }
+    }
+}
+
+impl<D> Forward for ConvTranspose<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    fn forward(&self) {
+        let operand = self.operand_data.borrow();
+        let weight = self.weight_data.borrow();
+
+        let full_spatial: Vec<usize> = self
+            .scattered
+            .borrow()
+            .shape()
+            .iter()
+            .skip(2)
+            .copied()
+            .collect();
+        let scattered = transpose(&operand.view().into_dyn(), &weight.view().into_dyn(), &full_spatial, &self.stride, &self.dilation);
+
+        self.scattered.borrow_mut().assign(
+            &scattered
+                .clone()
+                .into_dimensionality::<D>()
+                .expect("error: scattered conv_transpose output has unexpected rank"),
+        );
+
+        let cropped = scattered.slice_each_axis(|ax_desc| {
+            let axis = ax_desc.axis.index();
+            if axis < 2 {
+                ndarray::Slice::from(..)
+            } else {
+                let c = self.crop[axis - 2] as isize;
+                let len = scattered.shape()[axis] as isize - c;
+                ndarray::Slice::from(..len.max(0))
+            }
+        }).to_owned();
+
+        self.data.borrow_mut().assign(
+            &cropped
+                .into_dimensionality::<D>()
+                .expect("error: conv_transpose output has unexpected rank"),
+        );
+    }
+}
+
+pub(crate) struct ConvTransposeBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    operand_data: Shared<Array<f32, D>>,
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    weight_data: Shared<Array<f32, D>>,
+    weight_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    crop: Vec<usize>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+}
+
+impl<D> ConvTransposeBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        weight_data: Shared<Array<f32, D>>,
+        weight_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        crop: Vec<usize>,
+        stride: Vec<usize>,
+        dilation: Vec<usize>,
+    ) -> Self {
+        Self {
+            operand_data,
+            operand_gradient,
+            weight_data,
+            weight_gradient,
+            gradient,
+            crop,
+            stride,
+            dilation,
+        }
+    }
+}
+
+impl<D> Backward for ConvTransposeBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    fn backward(&self) {
+        let operand = self.operand_data.borrow();
+        let weight = self.weight_data.borrow();
+        let grad = self.gradient.borrow();
+
+        let mut padded_shape = grad.raw_dim();
+        for (axis, &c) in self.crop.iter().enumerate() {
+            padded_shape[axis + 2] += c;
+        }
+        let mut grad_output = ArrayD::zeros(padded_shape);
+        {
+            let mut inner = grad_output.slice_each_axis_mut(|ax_desc| {
+                let axis = ax_desc.axis.index();
+                if axis < 2 {
+                    ndarray::Slice::from(..)
+                } else {
+                    ndarray::Slice::from(..grad.shape()[axis] as isize)
+                }
+            });
+            inner.assign(&grad.view().into_dyn());
+        }
+
+        let (grad_input, grad_weight) = backward(
+            &operand.view().into_dyn(),
+            &weight.view().into_dyn(),
+            &grad_output.view(),
+            &self.stride,
+            &self.dilation,
+        );
+        let grad_input = grad_input
+            .into_dimensionality::<D>()
+            .expect("error: conv_transpose input gradient has unexpected rank");
+        let grad_weight = grad_weight
+            .into_dimensionality::<D>()
+            .expect("error: conv_transpose weight gradient has unexpected rank");
+
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&grad_input)
+            .for_each(|grad_el, &contribution| *grad_el += contribution);
+        Zip::from(&mut *self.weight_gradient.borrow_mut())
+            .and(&grad_weight)
+            .for_each(|grad_el, &contribution| *grad_el += contribution);
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// #[cfg(test)]
+// mod test;
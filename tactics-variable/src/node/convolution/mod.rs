@@ -0,0 +1,421 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::rc::Rc;
+
+use ndarray::{Array, ArrayD, ArrayViewD, Axis, Dimension, IxDyn, RemoveAxis, Slice, Zip};
+
+use crate::{
+    autograd::{Backward, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// Walks every multi-index of a (row-major) shape with `dims` elements per axis, in ascending
+/// order.
+fn indices(dims: &[usize]) -> impl Iterator<Item = Vec<usize>> + '_ {
+    let total: usize = dims.iter().product();
+    (0..total).map(move |flat| {
+        let mut flat = flat;
+        let mut index = vec![0; dims.len()];
+        for axis in (0..dims.len()).rev() {
+            index[axis] = flat % dims[axis];
+            flat /= dims[axis];
+        }
+        index
+    })
+}
+
+/// Spatial extent of a convolution's output given the (already padded) spatial extent of its
+/// input.
+fn out_spatial(in_spatial: &[usize], kernel: &[usize], stride: &[usize], dilation: &[usize]) -> Vec<usize> {
+    (0..kernel.len())
+        .map(|axis| (in_spatial[axis] - dilation[axis] * (kernel[axis] - 1) - 1) / stride[axis] + 1)
+        .collect()
+}
+
+/// Direct, nested-loop cross-correlation: accumulates one output element at a time straight from
+/// `input` and `weight`, without materializing an intermediate column matrix.
+///
+/// `input` is `(N, Cin, *spatial)`, already padded; `weight` is `(Cout, Cin / groups, *kernel)`.
+/// Returns `(N, Cout, *out_spatial)`.
+fn direct(input: &ArrayViewD<f32>, weight: &ArrayViewD<f32>, stride: &[usize], dilation: &[usize], groups: usize) -> ArrayD<f32> {
+    let batch = input.shape()[0];
+    let out_channels = weight.shape()[0];
+    let in_channels_per_group = weight.shape()[1];
+    let out_channels_per_group = out_channels / groups;
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let out_spatial = out_spatial(&input.shape()[2..], &kernel_shape, stride, dilation);
+
+    let mut out_shape = vec![batch, out_channels];
+    out_shape.extend(&out_spatial);
+    let mut output = ArrayD::zeros(IxDyn(&out_shape));
+
+    for n in 0..batch {
+        for group in 0..groups {
+            for oc in 0..out_channels_per_group {
+                let out_channel = group * out_channels_per_group + oc;
+                for out_pos in indices(&out_spatial) {
+                    let mut acc = 0.;
+                    for ic in 0..in_channels_per_group {
+                        let in_channel = group * in_channels_per_group + ic;
+                        for k_pos in indices(&kernel_shape) {
+                            let in_pos: Vec<usize> = out_pos
+                                .iter()
+                                .zip(&k_pos)
+                                .enumerate()
+                                .map(|(axis, (&o, &k))| o * stride[axis] + k * dilation[axis])
+                                .collect();
+
+                            let mut in_index = vec![n, in_channel];
+                            in_index.extend(in_pos);
+                            let mut w_index = vec![out_channel, ic];
+                            w_index.extend(k_pos);
+
+                            acc += input[IxDyn(&in_index)] * weight[IxDyn(&w_index)];
+                        }
+                    }
+
+                    let mut out_index = vec![n, out_channel];
+                    out_index.extend(out_pos);
+                    output[IxDyn(&out_index)] = acc;
+                }
+            }
+        }
+    }
+
+    output
+}
+
+/// im2col/GEMM cross-correlation, see [`direct`] for the contract. Cheaper than [`direct`] for
+/// large kernels/channel counts, where one big GEMM amortizes the cost of materializing the
+/// column matrix.
+fn im2col(input: &ArrayViewD<f32>, weight: &ArrayViewD<f32>, stride: &[usize], dilation: &[usize], groups: usize) -> ArrayD<f32> {
+    let batch = input.shape()[0];
+    let out_channels = weight.shape()[0];
+    let in_channels_per_group = weight.shape()[1];
+    let out_channels_per_group = out_channels / groups;
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let out_spatial = out_spatial(&input.shape()[2..], &kernel_shape, stride, dilation);
+
+    let positions: Vec<(usize, Vec<usize>)> = (0..batch)
+        .flat_map(|n| indices(&out_spatial).map(move |out_pos| (n, out_pos)))
+        .collect();
+    let receptive_field: Vec<(usize, Vec<usize>)> = (0..in_channels_per_group)
+        .flat_map(|ic| indices(&kernel_shape).map(move |k_pos| (ic, k_pos)))
+        .collect();
+
+    let mut out_shape = vec![batch, out_channels];
+    out_shape.extend(&out_spatial);
+    let mut output = ArrayD::zeros(IxDyn(&out_shape));
+
+    for group in 0..groups {
+        let mut columns = Array::<f32, _>::zeros((receptive_field.len(), positions.len()));
+        for (column, (n, out_pos)) in positions.iter().enumerate() {
+            for (row, (ic, k_pos)) in receptive_field.iter().enumerate() {
+                let in_channel = group * in_channels_per_group + ic;
+                let in_pos: Vec<usize> = out_pos
+                    .iter()
+                    .zip(k_pos)
+                    .enumerate()
+                    .map(|(axis, (&o, &k))| o * stride[axis] + k * dilation[axis])
+                    .collect();
+
+                let mut in_index = vec![*n, in_channel];
+                in_index.extend(in_pos);
+                columns[[row, column]] = input[IxDyn(&in_index)];
+            }
+        }
+
+        let group_start = (group * out_channels_per_group) as isize;
+        let kernel_matrix = weight
+            .slice_axis(Axis(0), Slice::from(group_start..group_start + out_channels_per_group as isize))
+            .to_owned()
+            .into_shape((out_channels_per_group, receptive_field.len()))
+            .expect("error: kernel slice could not be reshaped into a (Cout/groups, Cin/groups * kernel_elems) matrix");
+
+        let group_output = kernel_matrix.dot(&columns);
+
+        for (column, (n, out_pos)) in positions.iter().enumerate() {
+            for oc in 0..out_channels_per_group {
+                let out_channel = group * out_channels_per_group + oc;
+                let mut out_index = vec![*n, out_channel];
+                out_index.extend(out_pos.clone());
+                output[IxDyn(&out_index)] = group_output[[oc, column]];
+            }
+        }
+    }
+
+    output
+}
+
+/// Gradient of a cross-correlation with respect to its (already padded) input and its weight: the
+/// col2im counterpart of [`direct`]/[`im2col`], scattering each output position's upstream
+/// gradient, scaled by the corresponding weight, back into every overlapping padded input
+/// position it was computed from — and, symmetrically, weighting it by the corresponding padded
+/// input element to accumulate the weight's gradient.
+fn backward(
+    padded: &ArrayViewD<f32>,
+    weight: &ArrayViewD<f32>,
+    grad_output: &ArrayViewD<f32>,
+    stride: &[usize],
+    dilation: &[usize],
+    groups: usize,
+) -> (ArrayD<f32>, ArrayD<f32>) {
+    let batch = padded.shape()[0];
+    let out_channels = weight.shape()[0];
+    let in_channels_per_group = weight.shape()[1];
+    let out_channels_per_group = out_channels / groups;
+    let kernel_shape = weight.shape()[2..].to_vec();
+    let out_spatial = grad_output.shape()[2..].to_vec();
+
+    let mut grad_padded = ArrayD::zeros(padded.raw_dim());
+    let mut grad_weight = ArrayD::zeros(weight.raw_dim());
+
+    for n in 0..batch {
+        for group in 0..groups {
+            for oc in 0..out_channels_per_group {
+                let out_channel = group * out_channels_per_group + oc;
+                for out_pos in indices(&out_spatial) {
+                    let mut go_index = vec![n, out_channel];
+                    go_index.extend(out_pos.clone());
+                    let grad_out_el = grad_output[IxDyn(&go_index)];
+
+                    for ic in 0..in_channels_per_group {
+                        let in_channel = group * in_channels_per_group + ic;
+                        for k_pos in indices(&kernel_shape) {
+                            let in_pos: Vec<usize> = out_pos
+                                .iter()
+                                .zip(&k_pos)
+                                .enumerate()
+                                .map(|(axis, (&o, &k))| o * stride[axis] + k * dilation[axis])
+                                .collect();
+
+                            let mut in_index = vec![n, in_channel];
+                            in_index.extend(in_pos);
+                            let mut w_index = vec![out_channel, ic];
+                            w_index.extend(k_pos);
+
+                            grad_padded[IxDyn(&in_index)] += grad_out_el * weight[IxDyn(&w_index)];
+                            grad_weight[IxDyn(&w_index)] += grad_out_el * padded[IxDyn(&in_index)];
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    (grad_padded, grad_weight)
+}
+
+/// Zero-pads every sample of `input` by `padding` on both sides of each spatial axis.
+fn pad(input: &ArrayD<f32>, padding: &[usize]) -> ArrayD<f32> {
+    let mut padded_shape = input.raw_dim();
+    for (axis, &p) in padding.iter().enumerate() {
+        padded_shape[axis + 1] += 2 * p;
+    }
+
+    let mut padded = ArrayD::zeros(padded_shape);
+    {
+        let mut inner = padded.slice_each_axis_mut(|ax_desc| {
+            let axis = ax_desc.axis.index();
+            if axis == 0 {
+                Slice::from(..)
+            } else {
+                let p = padding[axis - 1] as isize;
+                Slice::from(p..p + input.shape()[axis] as isize)
+            }
+        });
+        inner.assign(input);
+    }
+
+    padded
+}
+
+/// Crops the `padding` border back off of a gradient shaped like a zero-padded input, leaving one
+/// shaped like the original (unpadded) input.
+fn crop(padded: &ArrayD<f32>, padding: &[usize]) -> ArrayD<f32> {
+    padded
+        .slice_each_axis(|ax_desc| {
+            let axis = ax_desc.axis.index();
+            if axis == 0 {
+                Slice::from(..)
+            } else {
+                let p = padding[axis - 1] as isize;
+                let len = padded.shape()[axis] as isize - 2 * p;
+                Slice::from(p..p + len)
+            }
+        })
+        .to_owned()
+}
+
+/// A batched, optionally grouped, N-dimensional cross-correlation (what deep learning libraries
+/// usually call "convolution"): `data = conv(pad(operand_data), weight_data)`.
+///
+/// Padding is treated as a non-differentiable preprocessing step baked into this node — like
+/// [`tactics_core::Convolution`](tactics_core::Convolution) before it, this repository has no
+/// padding node of its own (see [`crate::node::pad`]), so [`ConvolutionBackward`] crops the
+/// padding border back off of the gradient rather than scattering it through whatever
+/// [`PaddingMode`](crate::PaddingMode) produced it.
+pub(crate) struct Convolution<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    operand_data: Shared<Array<f32, D>>,
+    weight_data: Shared<Array<f32, D>>,
+    padded: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    padding: Vec<usize>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+    groups: usize,
+    direct: bool,
+}
+
+impl<D> Convolution<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        weight_data: Shared<Array<f32, D>>,
+        padded: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        padding: Vec<usize>,
+        stride: Vec<usize>,
+        dilation: Vec<usize>,
+        groups: usize,
+        direct: bool,
+    ) -> Self {
+        Self {
+            operand_data,
+            weight_data,
+            padded,
+            data,
+            padding,
+            stride,
+            dilation,
+            groups,
+            direct,
+        }
+    }
+}
+
+impl<D> Forward for Convolution<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    fn forward(&self) {
+        let padded = pad(&self.operand_data.borrow().view().into_dyn().to_owned(), &self.padding);
+
+        let weight = self.weight_data.borrow();
+        let weight_view = weight.view().into_dyn();
+        let output = if self.direct {
+            direct(&padded.view(), &weight_view, &self.stride, &self.dilation, self.groups)
+        } else {
+            im2col(&padded.view(), &weight_view, &self.stride, &self.dilation, self.groups)
+        };
+
+        self.padded.borrow_mut().assign(
+            &padded
+                .into_dimensionality::<D>()
+                .expect("error: padded convolution input has unexpected rank"),
+        );
+        self.data.borrow_mut().assign(
+            &output
+                .into_dimensionality::<D>()
+                .expect("error: convolution output has unexpected rank"),
+        );
+    }
+}
+
+pub(crate) struct ConvolutionBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    padded: Shared<Array<f32, D>>,
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    weight_data: Shared<Array<f32, D>>,
+    weight_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    padding: Vec<usize>,
+    stride: Vec<usize>,
+    dilation: Vec<usize>,
+    groups: usize,
+}
+
+impl<D> ConvolutionBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn new(
+        padded: Shared<Array<f32, D>>,
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        weight_data: Shared<Array<f32, D>>,
+        weight_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        padding: Vec<usize>,
+        stride: Vec<usize>,
+        dilation: Vec<usize>,
+        groups: usize,
+    ) -> Self {
+        Self {
+            padded,
+            operand_gradient,
+            weight_data,
+            weight_gradient,
+            gradient,
+            padding,
+            stride,
+            dilation,
+            groups,
+        }
+    }
+}
+
+impl<D> Backward for ConvolutionBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    fn backward(&self) {
+        let padded = self.padded.borrow();
+        let weight = self.weight_data.borrow();
+
+        let (grad_padded, grad_weight) = backward(
+            &padded.view().into_dyn(),
+            &weight.view().into_dyn(),
+            &self.gradient.borrow().view().into_dyn(),
+            &self.stride,
+            &self.dilation,
+            self.groups,
+        );
+        let grad_input = crop(&grad_padded, &self.padding)
+            .into_dimensionality::<D>()
+            .expect("error: convolution input gradient has unexpected rank");
+        let grad_weight = grad_weight
+            .into_dimensionality::<D>()
+            .expect("error: convolution weight gradient has unexpected rank");
+
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&grad_input)
+            .for_each(|grad_el, &contribution| *grad_el += contribution);
+        Zip::from(&mut *self.weight_gradient.borrow_mut())
+            .and(&grad_weight)
+            .for_each(|grad_el, &contribution| *grad_el += contribution);
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// #[cfg(test)]
+// mod test;
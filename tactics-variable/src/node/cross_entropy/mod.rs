@@ -0,0 +1,140 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::rc::Rc;
+
+use ndarray::{Array, Axis, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// Fused log-softmax + negative-log-likelihood loss.
+///
+/// Operates directly on raw logits, computing `-log_softmax(logits)[target]` lane by lane along
+/// `axis` without ever materializing `softmax` followed by `log`, which keeps the loss finite for
+/// large logits.
+pub(crate) struct CrossEntropy<D>
+    where
+        D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    target: Shared<Array<f32, D::Smaller>>,
+    softmax: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D::Smaller>>,
+    axis: Axis,
+}
+
+impl<D> CrossEntropy<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        target: Shared<Array<f32, D::Smaller>>,
+        softmax: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D::Smaller>>,
+        axis: usize,
+    ) -> Self {
+        Self {
+            operand_data,
+            target,
+            softmax,
+            data,
+            axis: Axis(axis),
+        }
+    }
+}
+
+impl<D> Forward for CrossEntropy<D>
+    where
+        D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(self.operand_data.borrow().lanes(self.axis))
+            .and(self.softmax.borrow_mut().lanes_mut(self.axis))
+            .and(&*self.target.borrow())
+            .for_each(|loss_el, lane_o, mut softmax_lane, &target_el| {
+                let max = lane_o.fold(f32::MIN, |x, &y| x.max(y));
+                let exp = lane_o.map(|&el| (el - max).exp());
+                let sum = exp.sum();
+
+                Zip::from(&mut softmax_lane)
+                    .and(&exp)
+                    .for_each(|softmax_el, &exp_el| *softmax_el = exp_el / sum);
+
+                let target_idx = target_el as usize;
+                let log_softmax_target = lane_o[target_idx] - max - sum.ln();
+                *loss_el = -log_softmax_target;
+            });
+    }
+}
+
+pub(crate) struct CrossEntropyBackward<D>
+    where
+        D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    softmax: Shared<Array<f32, D>>,
+    target: Shared<Array<f32, D::Smaller>>,
+    gradient: Rc<Gradient<Array<f32, D::Smaller>, D::Smaller>>,
+    axis: Axis,
+}
+
+impl<D> CrossEntropyBackward<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        softmax: Shared<Array<f32, D>>,
+        target: Shared<Array<f32, D::Smaller>>,
+        gradient: Rc<Gradient<Array<f32, D::Smaller>, D::Smaller>>,
+        axis: usize,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            softmax,
+            target,
+            gradient,
+            axis: Axis(axis),
+        }
+    }
+}
+
+impl<D> Backward for CrossEntropyBackward<D>
+    where
+        D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(self.operand_gradient.borrow_mut().lanes_mut(self.axis))
+            .and(self.softmax.borrow().lanes(self.axis))
+            .and(&*self.gradient.borrow())
+            .and(&*self.target.borrow())
+            .for_each(|mut op_grad_lane, softmax_lane, &grad_el, &target_el| {
+                let target_idx = target_el as usize;
+                Zip::indexed(&mut op_grad_lane).and(&softmax_lane).for_each(
+                    |i, op_grad_el, &softmax_el| {
+                        let onehot = if i == target_idx { 1. } else { 0. };
+                        *op_grad_el += grad_el * (softmax_el - onehot)
+                    },
+                );
+            });
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// #[cfg(test)]
+// mod test;
@@ -0,0 +1,106 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::rc::Rc;
+
+use ndarray::{Array, Axis, Dimension, RemoveAxis, Zip};
+
+use crate::{
+    autograd::{Backward, Forward},
+    gradient::Gradient,
+    sparse_gradient::SparseGradient,
+    utils::Shared,
+};
+
+/// Embedding lookup: gathers the rows of `operand_data` indexed by `indices` along its outermost
+/// axis into `data`.
+///
+/// Used to index into a large parameter table (e.g. an embedding matrix) a handful of rows at a
+/// time without ever touching the rows that were not looked up.
+pub(crate) struct Gather<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    operand_data: Shared<Array<f32, D>>,
+    indices: Vec<usize>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> Gather<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        indices: Vec<usize>,
+        data: Shared<Array<f32, D>>,
+    ) -> Self {
+        Self {
+            operand_data,
+            indices,
+            data,
+        }
+    }
+}
+
+impl<D> Forward for Gather<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    fn forward(&self) {
+        let operand_data = self.operand_data.borrow();
+        Zip::from(self.data.borrow_mut().axis_iter_mut(Axis(0)))
+            .and(&self.indices)
+            .for_each(|mut row, &index| row.assign(&operand_data.index_axis(Axis(0), index)));
+    }
+}
+
+pub(crate) struct GatherBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    operand_gradient: Rc<SparseGradient<D::Smaller>>,
+    indices: Vec<usize>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> GatherBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<SparseGradient<D::Smaller>>,
+        indices: Vec<usize>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            indices,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for GatherBackward<D>
+    where
+        D: Dimension + RemoveAxis,
+{
+    fn backward(&self) {
+        Zip::from(self.gradient.borrow().axis_iter(Axis(0)))
+            .and(&self.indices)
+            .for_each(|grad_row, &index| self.operand_gradient.accumulate(index, grad_row));
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// #[cfg(test)]
+// mod test;
@@ -0,0 +1,106 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::rc::Rc;
+
+use ndarray::{Array, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// `sqrt(2 / pi)`, used by the tanh approximation of the GELU activation.
+const SQRT_2_OVER_PI: f32 = 0.797_884_6;
+const GELU_COEFF: f32 = 0.044715;
+
+pub(crate) struct GELU<D>
+    where
+        D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+}
+
+impl<D> GELU<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new(operand_data: Shared<Array<f32, D>>, data: Shared<Array<f32, D>>) -> Self {
+        Self { operand_data, data }
+    }
+}
+
+impl<D> Forward for GELU<D>
+    where
+        D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(&mut *self.data.borrow_mut())
+            .and(&*self.operand_data.borrow())
+            .for_each(|v, &o| {
+                let u = SQRT_2_OVER_PI * (o + GELU_COEFF * o.powi(3));
+                *v = 0.5 * o * (1. + u.tanh())
+            });
+    }
+}
+
+pub(crate) struct GELUBackward<D>
+    where
+        D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    operand_data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+}
+
+impl<D> GELUBackward<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        operand_data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            operand_data,
+            gradient,
+        }
+    }
+}
+
+impl<D> Backward for GELUBackward<D>
+    where
+        D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(&mut *self.operand_gradient.borrow_mut())
+            .and(&*self.gradient.borrow())
+            .and(&*self.operand_data.borrow())
+            .for_each(|op_grad_el, &grad_el, &op_data_el| {
+                let u = SQRT_2_OVER_PI * (op_data_el + GELU_COEFF * op_data_el.powi(3));
+                let tanh_u = u.tanh();
+                let sech2_u = 1. - tanh_u * tanh_u;
+                let du_dx = SQRT_2_OVER_PI * (1. + 3. * GELU_COEFF * op_data_el.powi(2));
+                let local_derivative = 0.5 * (1. + tanh_u) + 0.5 * op_data_el * sech2_u * du_dx;
+
+                *op_grad_el += grad_el * local_derivative
+            });
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// #[cfg(test)]
+// mod test;
@@ -15,9 +15,13 @@ mod bce_with_logits;
 mod chunk;
 mod concatenate;
 mod convolution;
+mod conv_transpose;
+mod cross_entropy;
 mod division;
 mod dropout;
 mod exp;
+mod gather;
+mod gelu;
 mod kldiv;
 mod leaky_relu;
 mod logn;
@@ -26,6 +30,7 @@ mod matrix_matrix_mul;
 mod matrix_matrix_mul_t;
 mod matrix_vector_mul;
 mod mean;
+mod mish;
 mod multi_concatenate;
 mod multi_stack;
 mod multiplication;
@@ -33,8 +38,10 @@ mod negation;
 mod nll;
 mod pad;
 mod power;
+mod quiet_softmax;
 mod relu;
 mod sigmoid;
+mod silu;
 mod softmax;
 mod softplus;
 mod sqrt;
@@ -55,9 +62,13 @@ pub(crate) use bce_with_logits::*;
 pub(crate) use chunk::*;
 pub(crate) use concatenate::*;
 pub(crate) use convolution::*;
+pub(crate) use conv_transpose::*;
+pub(crate) use cross_entropy::*;
 pub(crate) use division::*;
 pub(crate) use dropout::*;
 pub(crate) use exp::*;
+pub(crate) use gather::*;
+pub(crate) use gelu::*;
 pub(crate) use kldiv::*;
 pub(crate) use leaky_relu::*;
 pub(crate) use logn::*;
@@ -66,6 +77,7 @@ pub(crate) use matrix_matrix_mul::*;
 pub(crate) use matrix_matrix_mul_t::*;
 pub(crate) use matrix_vector_mul::*;
 pub(crate) use mean::*;
+pub(crate) use mish::*;
 pub(crate) use multi_concatenate::*;
 pub(crate) use multi_stack::*;
 pub(crate) use multiplication::*;
@@ -73,8 +85,10 @@ pub(crate) use negation::*;
 pub(crate) use nll::*;
 pub(crate) use pad::*;
 pub(crate) use power::*;
+pub(crate) use quiet_softmax::*;
 pub(crate) use relu::*;
 pub(crate) use sigmoid::*;
+pub(crate) use silu::*;
 pub(crate) use softmax::*;
 pub(crate) use softplus::*;
 pub(crate) use sqrt::*;
@@ -88,4 +102,4 @@ pub(crate) use unsqueeze::*;
 pub(crate) use vector_matrix_mul::*;
 pub(crate) use vector_vector_mul::*;
 
-pub use pad::{Constant, PaddingMode, Reflective, Replicative, Zero};
\ No newline at end of file
+pub use pad::{Circular, Constant, PaddingMode, Reflective, Replicative, Zero};
\ No newline at end of file
@@ -0,0 +1,47 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{ArrayView, ArrayViewMut, Dimension, RemoveAxis};
+
+use super::{pad_with, PaddingMode, SampleDim};
+
+/// Circular (a.k.a. wrap-around) padding.
+///
+/// The padding region wraps around to the opposite border of `base`, e.g. padding `[a, b, c]` by 2
+/// on each side gives `[b, c, a, b, c, a, b]`.
+#[derive(Copy, Clone, Debug)]
+pub struct Circular;
+
+impl<D> PaddingMode<D> for Circular
+    where
+        D: Dimension,
+        D::Smaller: RemoveAxis,
+{
+    fn pad(
+        &self,
+        padded: &mut ArrayViewMut<f32, SampleDim<D>>,
+        base: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        pad_with(
+            padded,
+            base,
+            padding,
+            // The left padding wraps back to the tail of `base`, the right padding wraps back to
+            // its head; both indices are taken modulo `inner_len` so padding wider than `base`
+            // just wraps around more than once.
+            |distance, inner_len| (inner_len - distance % inner_len) % inner_len,
+            |distance, inner_len| (distance - 1) % inner_len,
+        );
+    }
+}
+
+// #[cfg(test)]
+// mod test;
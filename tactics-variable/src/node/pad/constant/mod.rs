@@ -0,0 +1,42 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{ArrayView, ArrayViewMut, Axis, Dimension, RemoveAxis, Slice};
+
+use super::{PaddingMode, SampleDim};
+
+/// Constant padding.
+#[derive(Copy, Clone, Debug)]
+pub struct Constant(pub f32);
+
+impl<D> PaddingMode<D> for Constant
+    where
+        D: Dimension,
+        D::Smaller: RemoveAxis,
+{
+    fn pad(
+        &self,
+        padded: &mut ArrayViewMut<f32, SampleDim<D>>,
+        base: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        padded.fill(self.0);
+
+        let mut inner = padded.slice_each_axis_mut(|ax_desc| {
+            let axis = ax_desc.axis.index();
+            let pad = padding[axis] as isize;
+            Slice::from(pad..pad + base.len_of(Axis(axis)) as isize)
+        });
+        inner.assign(base);
+    }
+}
+
+// #[cfg(test)]
+// mod test;
@@ -0,0 +1,115 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{ArrayView, ArrayViewMut, Axis, Dimension, RemoveAxis, Slice, Zip};
+
+mod circular;
+mod constant;
+mod padding_mode;
+mod reflective;
+mod replicative;
+mod zero;
+
+pub(crate) use constant::*;
+pub(crate) use padding_mode::*;
+pub(crate) use zero::*;
+
+pub use circular::Circular;
+pub use reflective::Reflective;
+pub use replicative::Replicative;
+
+/// Shape of a single, batch-less sample, i.e. the dimensionality a [`PaddingMode`] actually pads.
+pub(crate) type SampleDim<D> = <D as Dimension>::Smaller;
+
+/// Copies `base` into the centre of `padded` and then extends it one axis at a time, filling the
+/// padding region on both sides of each axis with the value returned by `left_source`/
+/// `right_source`.
+///
+/// Both closures receive the 1-based distance of the padded position from the edge of `base`
+/// (`1` is the position immediately next to `base`) along with `base`'s length on that axis, and
+/// must return the index, within `base`'s range along that axis, to copy from.
+///
+/// Axes are extended in order so that, by the time axis `k` is processed, every axis `< k` has
+/// already been fully padded; this is what makes the corners of the padded region consistent with
+/// the rest of the border.
+pub(crate) fn pad_with<D, FL, FR>(
+    padded: &mut ArrayViewMut<f32, D>,
+    base: &ArrayView<f32, D>,
+    padding: D,
+    left_source: FL,
+    right_source: FR,
+) where
+    D: Dimension,
+    D::Smaller: RemoveAxis,
+    FL: Fn(usize, usize) -> usize + Copy,
+    FR: Fn(usize, usize) -> usize + Copy,
+{
+    {
+        let mut inner = padded.slice_each_axis_mut(|ax_desc| {
+            let axis = ax_desc.axis.index();
+            let pad = padding[axis] as isize;
+            Slice::from(pad..pad + base.len_of(Axis(axis)) as isize)
+        });
+        inner.assign(base);
+    }
+
+    let inner_shape = base.raw_dim();
+    for axis in 0..padded.ndim() {
+        extend_axis(padded, axis, &padding, &inner_shape, left_source, right_source);
+    }
+}
+
+/// Extends a single `axis` of `padded` on both sides, assuming every axis `< axis` is already
+/// fully padded and every axis `> axis` still only holds valid data in its interior range.
+fn extend_axis<D, FL, FR>(
+    padded: &mut ArrayViewMut<f32, D>,
+    axis: usize,
+    padding: &D,
+    inner_shape: &D,
+    left_source: FL,
+    right_source: FR,
+) where
+    D: Dimension,
+    FL: Fn(usize, usize) -> usize,
+    FR: Fn(usize, usize) -> usize,
+{
+    let pad = padding[axis];
+    if pad == 0 {
+        return;
+    }
+    let inner_len = inner_shape[axis];
+
+    let mut view = padded.view_mut();
+    let mut restricted = view.slice_each_axis_mut(|ax_desc| {
+        let j = ax_desc.axis.index();
+        if j <= axis {
+            Slice::from(..)
+        } else {
+            let p = padding[j] as isize;
+            Slice::from(p..p + inner_shape[j] as isize)
+        }
+    });
+
+    Zip::from(restricted.lanes_mut(Axis(axis))).for_each(|mut lane| {
+        let snapshot: Vec<f32> = (0..inner_len).map(|i| lane[pad + i]).collect();
+        for p in 0..pad {
+            // `p == 0` is the outermost position on the left and the innermost on the right, so
+            // the distance from the edge of `base` runs in opposite directions on the two sides.
+            let left_distance = pad - p;
+            let right_distance = p + 1;
+
+            lane[p] = snapshot[left_source(left_distance, inner_len)];
+            lane[pad + inner_len + p] = snapshot[right_source(right_distance, inner_len)];
+        }
+    });
+}
+
+#[cfg(test)]
+mod test;
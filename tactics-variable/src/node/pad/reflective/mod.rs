@@ -0,0 +1,46 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{ArrayView, ArrayViewMut, Dimension, RemoveAxis};
+
+use super::{pad_with, PaddingMode, SampleDim};
+
+/// Reflective (a.k.a. mirror) padding.
+///
+/// The padding region mirrors the values of `base` around its border, without repeating the
+/// border value itself, e.g. padding `[a, b, c]` by 2 on each side gives `[c, b, a, b, c, b, a]`.
+#[derive(Copy, Clone, Debug)]
+pub struct Reflective;
+
+impl<D> PaddingMode<D> for Reflective
+    where
+        D: Dimension,
+        D::Smaller: RemoveAxis,
+{
+    fn pad(
+        &self,
+        padded: &mut ArrayViewMut<f32, SampleDim<D>>,
+        base: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        // Mirrors `base` around its own border element without repeating it: the position at
+        // distance `d` from an edge reads back the element `d` steps in from that same edge.
+        pad_with(
+            padded,
+            base,
+            padding,
+            |distance, inner_len| distance.min(inner_len - 1),
+            |distance, inner_len| (inner_len - 1) - distance.min(inner_len - 1),
+        );
+    }
+}
+
+// #[cfg(test)]
+// mod test;
@@ -0,0 +1,44 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use ndarray::{ArrayView, ArrayViewMut, Dimension, RemoveAxis};
+
+use super::{pad_with, PaddingMode, SampleDim};
+
+/// Replicative (a.k.a. edge) padding.
+///
+/// The padding region repeats the nearest border value of `base`, e.g. padding `[a, b, c]` by 2 on
+/// each side gives `[a, a, a, b, c, c, c]`.
+#[derive(Copy, Clone, Debug)]
+pub struct Replicative;
+
+impl<D> PaddingMode<D> for Replicative
+    where
+        D: Dimension,
+        D::Smaller: RemoveAxis,
+{
+    fn pad(
+        &self,
+        padded: &mut ArrayViewMut<f32, SampleDim<D>>,
+        base: &ArrayView<f32, SampleDim<D>>,
+        padding: SampleDim<D>,
+    ) {
+        pad_with(
+            padded,
+            base,
+            padding,
+            |_distance, _inner_len| 0,
+            |_distance, inner_len| inner_len - 1,
+        );
+    }
+}
+
+// #[cfg(test)]
+// mod test;
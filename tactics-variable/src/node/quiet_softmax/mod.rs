@@ -0,0 +1,129 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::rc::Rc;
+
+use ndarray::{Array, Axis, Dimension, Zip};
+
+use crate::{
+    autograd::{Backward, Forward},
+    gradient::Gradient,
+    utils::Shared,
+};
+
+/// Like [`Softmax`](super::Softmax), but with an implicit extra logit of value `0` folded into the
+/// denominator: `out_i = exp(x_i) / (1 + sum_j exp(x_j))`. Unlike ordinary softmax, this lets a
+/// lane's outputs sum to *less* than `1` when every logit is strongly negative, letting e.g. an
+/// attention query "abstain" from attending to anything instead of being forced onto its least bad
+/// option.
+pub(crate) struct QuietSoftmax<D>
+    where
+        D: Dimension,
+{
+    operand_data: Shared<Array<f32, D>>,
+    data: Shared<Array<f32, D>>,
+    axis: Axis,
+}
+
+impl<D> QuietSoftmax<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new(
+        operand_data: Shared<Array<f32, D>>,
+        data: Shared<Array<f32, D>>,
+        axis: usize,
+    ) -> Self {
+        Self {
+            operand_data,
+            data,
+            axis: Axis(axis),
+        }
+    }
+}
+
+impl<D> Forward for QuietSoftmax<D>
+    where
+        D: Dimension,
+{
+    fn forward(&self) {
+        Zip::from(self.data.borrow_mut().lanes_mut(self.axis))
+            .and(self.operand_data.borrow().lanes(self.axis))
+            .for_each(|lane_v, lane_o| {
+                let max = lane_o.fold(f32::MIN, |x, &y| x.max(y)).max(0.);
+                let num = &lane_o.map(|&el| (el - max).exp());
+                let den = num.sum() + (-max).exp();
+                Zip::from(lane_v)
+                    .and(num)
+                    .for_each(|lane_v_el, &num_el| *lane_v_el = num_el / den);
+            });
+    }
+}
+
+/// The Jacobian-vector product of [`QuietSoftmax`] has the same `out_i * (grad_i - sum)` form as
+/// [`SoftmaxBackward`](super::SoftmaxBackward): the implicit extra logit is a constant with respect
+/// to the operand, so it shifts the forward pass's denominator without introducing any extra term
+/// into the derivative.
+pub(crate) struct QuietSoftmaxBackward<D>
+    where
+        D: Dimension,
+{
+    operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+    data: Shared<Array<f32, D>>,
+    gradient: Rc<Gradient<Array<f32, D>, D>>,
+    axis: Axis,
+}
+
+impl<D> QuietSoftmaxBackward<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new(
+        operand_gradient: Rc<Gradient<Array<f32, D>, D>>,
+        data: Shared<Array<f32, D>>,
+        gradient: Rc<Gradient<Array<f32, D>, D>>,
+        axis: usize,
+    ) -> Self {
+        Self {
+            operand_gradient,
+            data,
+            gradient,
+            axis: Axis(axis),
+        }
+    }
+}
+
+impl<D> Backward for QuietSoftmaxBackward<D>
+    where
+        D: Dimension,
+{
+    fn backward(&self) {
+        Zip::from(self.operand_gradient.borrow_mut().lanes_mut(self.axis))
+            .and(self.gradient.borrow().lanes(self.axis))
+            .and(self.data.borrow().lanes(self.axis))
+            .for_each(|mut op_grad_lane, grad_lane, data_lane| {
+                let sum = Zip::from(grad_lane)
+                    .and(data_lane)
+                    .fold(0., |acc, &grad_el, &data_el| acc + grad_el * data_el);
+                Zip::from(&mut op_grad_lane)
+                    .and(&grad_lane)
+                    .and(&data_lane)
+                    .for_each(|op_grad_el, &grad_el, &data_el| {
+                        *op_grad_el += data_el * (grad_el - sum)
+                    });
+            });
+    }
+}
+
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~ Tests ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// ~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~~
+// #[cfg(test)]
+// mod test;
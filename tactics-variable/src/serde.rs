@@ -37,7 +37,7 @@ impl<'d, D> Deserialize<'d> for Var<D>
         where
             De: Deserializer<'d>,
     {
-        let data = Array::<f32, D>::deserialize(deserializer).unwrap();
+        let data = Array::<f32, D>::deserialize(deserializer)?;
         Ok(Self::leaf(data))
     }
 }
@@ -62,7 +62,7 @@ impl<'d, D> Deserialize<'d> for VarDiff<D>
         where
             De: Deserializer<'d>,
     {
-        let data = Array::<f32, D>::deserialize(deserializer).unwrap();
+        let data = Array::<f32, D>::deserialize(deserializer)?;
         Ok(Var::leaf(data).requires_grad())
     }
 }
@@ -0,0 +1,93 @@
+/*
+ * This source file is part of the quint-lang.org open source project
+ *
+ * Copyright (c) 2023 quint-lang
+ * This program and the accompanying materials are made available under
+ * the terms of the MIT License which is available at https://opensource.org/license/mit
+ *
+ * See https://quint-lang.org/tactics for more information
+ */
+
+use std::{
+    cell::{Ref, RefCell},
+    collections::BTreeMap,
+};
+
+use ndarray::{Array, ArrayView, Dimension, Zip};
+
+/// Gradient of a large parameter table (e.g. an embedding matrix) of which, in a single backward
+/// pass, only a handful of rows are actually touched.
+///
+/// Rather than materializing and accumulating into a dense zero gradient the size of the whole
+/// table, a [`SparseGradient`] only stores the rows that were looked up, keyed by their index into
+/// the table's first axis. `D` is the shape of a *single row*, not of the table itself.
+pub struct SparseGradient<D>
+    where
+        D: Dimension,
+{
+    rows: RefCell<BTreeMap<usize, Array<f32, D>>>,
+}
+
+impl<D> SparseGradient<D>
+    where
+        D: Dimension,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            rows: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Accumulates `grad` into the gradient of `row`, matching the `+=` accumulation convention
+    /// used by dense gradients.
+    pub(crate) fn accumulate(&self, row: usize, grad: ArrayView<f32, D>) {
+        let mut rows = self.rows.borrow_mut();
+        match rows.get_mut(&row) {
+            Some(existing) => Zip::from(existing).and(&grad).for_each(|el, &grad_el| *el += grad_el),
+            None => {
+                rows.insert(row, grad.to_owned());
+            }
+        }
+    }
+
+    /// Clears every accumulated row, to be called once their gradient has been consumed.
+    pub(crate) fn clear(&self) {
+        self.rows.borrow_mut().clear();
+    }
+
+    /// Returns a view of the accumulated `(row index, row gradient)` pairs.
+    pub fn rows(&self) -> Ref<BTreeMap<usize, Array<f32, D>>> {
+        self.rows.borrow()
+    }
+
+    /// Expands this sparse gradient into a dense `Array` of shape `(table_rows, ..row shape..)`,
+    /// for code paths that still expect a full gradient (e.g. a dense-only optimizer).
+    pub fn to_dense(&self, table_rows: usize) -> Array<f32, D::Larger> {
+        let rows = self.rows.borrow();
+        let row_shape = rows
+            .values()
+            .next()
+            .map(|row| row.raw_dim())
+            .unwrap_or_else(|| D::zeros(D::NDIM.unwrap_or(0)));
+
+        let mut table_shape = D::Larger::zeros(row_shape.ndim() + 1);
+        table_shape[0] = table_rows;
+        table_shape.slice_mut()[1..].clone_from_slice(row_shape.slice());
+
+        let mut dense = Array::zeros(table_shape);
+        for (&index, row) in rows.iter() {
+            dense.index_axis_mut(ndarray::Axis(0), index).assign(row);
+        }
+
+        dense
+    }
+}
+
+impl<D> Default for SparseGradient<D>
+    where
+        D: Dimension,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}